@@ -0,0 +1,31 @@
+use crate::Day01;
+use common::{Generator, Rng};
+
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    // size frequency changes. The drift over a whole pass is kept smaller than the number of
+    // changes, so two running frequencies share a residue modulo the drift and a repeat is
+    // guaranteed to happen eventually.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1) as i64;
+        let mut deltas: Vec<i64> = (1..size)
+            .map(|_| {
+                let magnitude = rng.range(1, 20);
+                if rng.chance(0.5) {
+                    magnitude
+                } else {
+                    -magnitude
+                }
+            })
+            .collect();
+
+        let drift = rng.range(-(size - 1), size - 1);
+        deltas.push(drift - deltas.iter().sum::<i64>());
+
+        deltas
+            .iter()
+            .map(|delta| format!("{:+}\n", delta))
+            .collect()
+    }
+}
@@ -0,0 +1,336 @@
+use common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(common::parse_lines(input, parse_delta)?)
+    }
+
+    fn part1(deltas: &Self::Input) -> Result<Self::Part1> {
+        Ok(deltas.iter().map(|&delta| i64::from(delta)).sum())
+    }
+
+    fn part2(deltas: &Self::Input) -> Result<Self::Part2> {
+        first_repeat(deltas)
+            .map(|repeat| repeat.frequency)
+            .ok_or_else(|| From::from("the frequency never repeats"))
+    }
+}
+
+// a frequency change of the form +7 or -3
+fn parse_delta(line: &str) -> std::result::Result<i32, ParseError> {
+    let columns = common::trim_span(line, 0..line.len());
+
+    common::number(line, columns, "a frequency change such as +7 or -3")
+}
+
+/// A trace of the frequency as a list of changes is applied once, built up a change at a time so
+/// that the changes can be streamed from a reader.
+///
+/// Positions index into the running series, where position 0 is the starting frequency of 0 and
+/// position i is the frequency after the change on line i.
+#[derive(Debug, Clone)]
+pub struct Calibration {
+    deltas: Vec<i32>,
+    series: Vec<i64>,
+    min: i64,
+    max: i64,
+    // the position each frequency was first reached and whether it has been reached again
+    seen: HashMap<i64, (usize, bool)>,
+    repeats: Vec<Occurrence>,
+}
+
+/// A frequency reached more than once, along with the positions it was reached at the first and
+/// second time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrence {
+    pub frequency: i64,
+    pub first: usize,
+    pub second: usize,
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Calibration::new()
+    }
+}
+
+impl Calibration {
+    pub fn new() -> Self {
+        let mut seen = HashMap::new();
+        seen.insert(0, (0, false));
+
+        Calibration {
+            deltas: Vec::new(),
+            series: vec![0],
+            min: 0,
+            max: 0,
+            seen,
+            repeats: Vec::new(),
+        }
+    }
+
+    /// Reads one frequency change per line, failing on the first line that isn't one
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let mut calibration = Calibration::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let delta = parse_delta(&line?).map_err(|e| e.at_line(i + 1))?;
+            calibration.push(delta);
+        }
+
+        Ok(calibration)
+    }
+
+    pub fn push(&mut self, delta: i32) {
+        let frequency = self.frequency() + i64::from(delta);
+        let position = self.series.len();
+
+        self.deltas.push(delta);
+        self.series.push(frequency);
+        self.min = self.min.min(frequency);
+        self.max = self.max.max(frequency);
+
+        let (first, repeated) = self.seen.entry(frequency).or_insert((position, false));
+
+        // only the first time a frequency comes round again is recorded
+        if *first != position && !*repeated {
+            *repeated = true;
+            self.repeats.push(Occurrence {
+                frequency,
+                first: *first,
+                second: position,
+            });
+        }
+    }
+
+    pub fn deltas(&self) -> &[i32] {
+        &self.deltas
+    }
+
+    /// The running frequency at each position, starting from 0
+    pub fn series(&self) -> &[i64] {
+        &self.series
+    }
+
+    /// The frequency after every change so far
+    pub fn frequency(&self) -> i64 {
+        *self.series.last().unwrap()
+    }
+
+    /// The lowest frequency reached, including the starting frequency
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+
+    /// The highest frequency reached, including the starting frequency
+    pub fn max(&self) -> i64 {
+        self.max
+    }
+
+    /// How far the frequency moves over one pass of the changes
+    pub fn drift(&self) -> i64 {
+        self.frequency()
+    }
+
+    /// Every frequency reached more than once in a single pass, in the order they came round
+    /// again
+    pub fn repeats(&self) -> &[Occurrence] {
+        &self.repeats
+    }
+
+    /// The first frequency reached twice when the changes are applied over and over
+    pub fn first_repeat(&self) -> Option<Repeat> {
+        first_repeat(&self.deltas)
+    }
+}
+
+/// The first frequency that is reached twice and where it is reached for the second time. Both
+/// count from 0, cycle being the number of whole passes over the deltas before the one where
+/// the repeat happens and line the index of the delta that produces it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repeat {
+    pub frequency: i64,
+    pub cycle: u64,
+    pub line: usize,
+}
+
+/// Finds the first repeated frequency without cycling through the deltas, returning None if the
+/// frequency never repeats.
+///
+/// If nothing repeats during the first pass then every later frequency is one from the first
+/// pass plus a multiple of the drift, the change over a whole pass. Two frequencies can only
+/// ever meet if they are congruent modulo the drift, and a frequency is first reached again by
+/// the nearest one behind it in the direction of the drift, the gap between them divided by the
+/// drift being the number of passes it takes to get there.
+pub fn first_repeat(deltas: &[i32]) -> Option<Repeat> {
+    let n = deltas.len();
+    let mut freq: i64 = 0;
+    let mut seen = HashSet::new();
+    seen.insert(0);
+
+    for (line, &delta) in deltas.iter().enumerate() {
+        freq += i64::from(delta);
+
+        if !seen.insert(freq) {
+            return Some(Repeat {
+                frequency: freq,
+                cycle: 0,
+                line,
+            });
+        }
+    }
+
+    // the drift can't be 0 here, as the last frequency of the first pass would be the starting
+    // frequency again
+    let drift = freq;
+    if n == 0 {
+        return None;
+    }
+
+    // the starting frequency stands in for the last one of the first pass, which is the same
+    // frequency a pass later, so each frequency is keyed by the time it's first reached, with the
+    // start at time -1
+    let mut classes: HashMap<i64, Vec<(i64, i64)>> = HashMap::new();
+    let mut freq = 0;
+    classes.entry(0).or_default().push((0, -1));
+
+    for (time, &delta) in deltas[..n - 1].iter().enumerate() {
+        freq += i64::from(delta);
+        let class = classes.entry(freq.rem_euclid(drift.abs())).or_default();
+        class.push((freq, time as i64));
+    }
+
+    let mut first: Option<(i64, i64)> = None;
+
+    for class in classes.values_mut() {
+        class.sort_unstable();
+        if drift < 0 {
+            class.reverse();
+        }
+
+        for pair in class.windows(2) {
+            let ((from, time), (to, _)) = (pair[0], pair[1]);
+            let passes = (to - from) / drift;
+            let repeated_at = passes * n as i64 + time;
+
+            if first.is_none_or(|(at, _)| repeated_at < at) {
+                first = Some((repeated_at, to));
+            }
+        }
+    }
+
+    first.map(|(at, frequency)| Repeat {
+        frequency,
+        cycle: (at / n as i64) as u64,
+        line: (at % n as i64) as usize,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let deltas = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part1(&deltas).unwrap(), 3);
+
+        let deltas = Day01::parse("+2000000000\n+2000000000").unwrap();
+        assert_eq!(Day01::part1(&deltas).unwrap(), 4_000_000_000);
+    }
+
+    #[test]
+    fn part2() {
+        let deltas = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part2(&deltas).unwrap(), 2);
+
+        let frequency = |deltas: &[i32]| first_repeat(deltas).map(|repeat| repeat.frequency);
+        assert_eq!(frequency(&[1, -1]), Some(0));
+        assert_eq!(frequency(&[3, 3, 4, -2, -4]), Some(10));
+        assert_eq!(frequency(&[-6, 3, 8, 5, -6]), Some(5));
+        assert_eq!(frequency(&[7, 7, -2, -7, -4]), Some(14));
+    }
+
+    #[test]
+    fn never_repeats() {
+        assert_eq!(first_repeat(&[]), None);
+        assert_eq!(first_repeat(&[1, 2, 3]), None);
+        assert_eq!(first_repeat(&[-5, 1, -3]), None);
+        assert!(Day01::part2(&vec![1, 1]).is_err());
+    }
+
+    #[test]
+    fn calibration() {
+        let calibration = Calibration::from_reader(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(calibration.series(), &[0, 1, -1, 2, 3]);
+        assert_eq!((calibration.min(), calibration.max()), (-1, 3));
+        assert_eq!(calibration.drift(), 3);
+        assert!(calibration.repeats().is_empty());
+        assert_eq!(calibration.first_repeat().unwrap().frequency, 2);
+
+        let calibration = Calibration::from_reader("+1\n-1\n+1\n-1\n+2".as_bytes()).unwrap();
+        let repeats = [
+            Occurrence {
+                frequency: 0,
+                first: 0,
+                second: 2,
+            },
+            Occurrence {
+                frequency: 1,
+                first: 1,
+                second: 3,
+            },
+        ];
+        assert_eq!(calibration.repeats(), &repeats);
+        assert_eq!(calibration.max(), 2);
+
+        let e = Calibration::from_reader("+1\n+x\n".as_bytes()).unwrap_err();
+        assert!(e
+            .to_string()
+            .starts_with("line 2, column 1: invalid number `+x`"));
+    }
+
+    #[test]
+    fn position() {
+        // +7, +14, +12, +5, +1 then +8, +15, +13, +6, +2 then +9, +16, +14
+        let repeat = Repeat {
+            frequency: 14,
+            cycle: 2,
+            line: 2,
+        };
+        assert_eq!(first_repeat(&[7, 7, -2, -7, -4]), Some(repeat));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..200usize) {
+            let (_, deltas) = common::generated::<Day01>(seed, size);
+
+            prop_assert_eq!(first_repeat(&deltas), reference::first_repeat(&deltas, 500));
+        }
+
+        // small deltas, so any repeat happens within a few hundred passes
+        #[test]
+        fn matches_reference_without_repeats(deltas in prop::collection::vec(-10..=10i32, 0..20)) {
+            prop_assert_eq!(first_repeat(&deltas), reference::first_repeat(&deltas, 500));
+        }
+    }
+}
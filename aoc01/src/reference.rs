@@ -0,0 +1,26 @@
+// every frequency reached kept in a list, searched linearly after each change
+
+use crate::Repeat;
+
+// keeps every frequency reached so far in a list and searches it after each change, giving up
+// after the given number of passes over the deltas
+pub fn first_repeat(deltas: &[i32], passes: u64) -> Option<Repeat> {
+    let mut reached = vec![0];
+
+    for cycle in 0..passes {
+        for (line, &delta) in deltas.iter().enumerate() {
+            let frequency = reached.last().unwrap() + i64::from(delta);
+
+            if reached.contains(&frequency) {
+                return Some(Repeat {
+                    frequency,
+                    cycle,
+                    line,
+                });
+            }
+            reached.push(frequency);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,112 @@
+use crate::Result;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// How many times each character appears in an id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    counts: BTreeMap<char, usize>,
+}
+
+impl Histogram {
+    pub fn new(id: &str) -> Self {
+        let mut counts = BTreeMap::new();
+
+        for c in id.chars() {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        Histogram { counts }
+    }
+
+    /// How many times c appears
+    pub fn count(&self, c: char) -> usize {
+        self.counts.get(&c).copied().unwrap_or(0)
+    }
+
+    /// Each character that appears along with how many times it appears, in character order
+    pub fn counts(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        self.counts.iter().map(|(&c, &count)| (c, count))
+    }
+
+    /// Whether some character appears exactly n times
+    pub fn has_count(&self, n: usize) -> bool {
+        self.counts.values().any(|&count| count == n)
+    }
+}
+
+// e.g. a:1 b:3 c:2
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts: Vec<String> = self
+            .counts()
+            .map(|(c, count)| format!("{}:{}", c, count))
+            .collect();
+
+        write!(f, "{}", counts.join(" "))
+    }
+}
+
+/// How the tallies for each repeat count are combined into a checksum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+}
+
+/// A checksum over a list of ids. For each chosen repeat count n, the ids with some character
+/// appearing exactly n times are tallied, and the tallies are then combined. The puzzle's
+/// checksum multiplies the tallies for 2 and 3 and is the default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checksum {
+    pub repeats: Vec<usize>,
+    pub combine: Combine,
+}
+
+impl Default for Checksum {
+    fn default() -> Self {
+        Checksum::new(&[2, 3], Combine::Product)
+    }
+}
+
+impl Checksum {
+    pub fn new(repeats: &[usize], combine: Combine) -> Self {
+        Checksum {
+            repeats: repeats.to_vec(),
+            combine,
+        }
+    }
+
+    /// The number of ids with some character appearing exactly n times, for each repeat count n
+    pub fn tallies<S: AsRef<str>>(&self, ids: &[S]) -> Vec<u64> {
+        let mut tallies = vec![0; self.repeats.len()];
+
+        for id in ids {
+            let histogram = Histogram::new(id.as_ref());
+
+            for (tally, &n) in tallies.iter_mut().zip(self.repeats.iter()) {
+                if histogram.has_count(n) {
+                    *tally += 1;
+                }
+            }
+        }
+
+        tallies
+    }
+
+    /// Combines the tallies, failing if the checksum doesn't fit in a u64
+    pub fn compute<S: AsRef<str>>(&self, ids: &[S]) -> Result<u64> {
+        let mut tallies = self.tallies(ids).into_iter();
+        let checksum = match self.combine {
+            Combine::Product => tallies.try_fold(1u64, u64::checked_mul),
+            Combine::Sum => tallies.try_fold(0u64, u64::checked_add),
+        };
+
+        checksum.ok_or_else(|| From::from("checksum overflowed"))
+    }
+}
+
+/// The histogram of each id, in the same order as the ids
+pub fn histograms<S: AsRef<str>>(ids: &[S]) -> Vec<Histogram> {
+    ids.iter().map(|id| Histogram::new(id.as_ref())).collect()
+}
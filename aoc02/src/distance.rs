@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+/// How far apart two ids are
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// the number of positions at which ids of the same length differ, ids of different lengths
+    /// have no Hamming distance
+    Hamming,
+    /// the fewest single character insertions, deletions and substitutions turning one id into
+    /// the other
+    Levenshtein,
+}
+
+/// Two ids within some distance of each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    pub first: usize,
+    pub second: usize,
+    pub distance: usize,
+    /// the characters the two ids have in common once they are aligned
+    pub common: String,
+}
+
+impl Metric {
+    /// The distance between a and b, or None if the metric doesn't apply to them
+    pub fn distance(self, a: &str, b: &str) -> Option<usize> {
+        self.align(a, b).map(|(distance, _)| distance)
+    }
+
+    /// The distance between a and b along with the characters they share when aligned, in order.
+    /// For Hamming these are the characters that match at the same position, and for Levenshtein
+    /// the characters left untouched by a shortest edit.
+    pub fn align(self, a: &str, b: &str) -> Option<(usize, String)> {
+        let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+
+        match self {
+            Metric::Hamming if a.len() != b.len() => None,
+            Metric::Hamming => {
+                let common: String = a
+                    .iter()
+                    .zip(b.iter())
+                    .filter(|(x, y)| x == y)
+                    .map(|(&x, _)| x)
+                    .collect();
+
+                Some((a.len() - common.chars().count(), common))
+            }
+            Metric::Levenshtein => Some(levenshtein(&a, &b)),
+        }
+    }
+}
+
+// distances[i][j] is the distance between the first i characters of a and the first j of b, the
+// common characters are recovered by walking back from the end preferring matches
+fn levenshtein(a: &[char], b: &[char]) -> (usize, String) {
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = distances[i - 1][j - 1] + usize::from(a[i - 1] != b[j - 1]);
+            let deletion = distances[i - 1][j] + 1;
+            let insertion = distances[i][j - 1] + 1;
+
+            distances[i][j] = substitution.min(deletion).min(insertion);
+        }
+    }
+
+    let (mut i, mut j) = (a.len(), b.len());
+    let mut common = Vec::new();
+
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] && distances[i][j] == distances[i - 1][j - 1] {
+            common.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if distances[i][j] == distances[i - 1][j - 1] + 1 {
+            i -= 1;
+            j -= 1;
+        } else if distances[i][j] == distances[i - 1][j] + 1 {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    (
+        distances[a.len()][b.len()],
+        common.into_iter().rev().collect(),
+    )
+}
+
+/// Every pair of ids within distance k of each other under the metric, ordered by the indices of
+/// the pair
+pub fn within<S: AsRef<str>>(ids: &[S], metric: Metric, k: usize) -> Vec<Match> {
+    let lengths: Vec<usize> = ids.iter().map(|id| id.as_ref().chars().count()).collect();
+    let mut matches = Vec::new();
+
+    for (first, a) in ids.iter().enumerate() {
+        for (second, b) in ids.iter().enumerate().skip(first + 1) {
+            // ids whose lengths differ by more than k can't be within k of each other
+            if lengths[first].abs_diff(lengths[second]) > k {
+                continue;
+            }
+
+            if let Some((distance, common)) = metric.align(a.as_ref(), b.as_ref()) {
+                if distance <= k {
+                    matches.push(Match {
+                        first,
+                        second,
+                        distance,
+                        common,
+                    });
+                }
+            }
+        }
+    }
+
+    matches
+}
+
+/// Groups the ids into clusters, where ids within distance k of each other are in the same
+/// cluster, along with anything within k of either of them and so on. Each cluster is a list of
+/// indices in ascending order, and ids that are not within k of any other id are left out.
+pub fn clusters<S: AsRef<str>>(ids: &[S], metric: Metric, k: usize) -> Vec<Vec<usize>> {
+    // each id points towards the representative of its cluster
+    let mut parents: Vec<usize> = (0..ids.len()).collect();
+
+    fn find(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let matches = within(ids, metric, k);
+    for m in matches.iter() {
+        let (a, b) = (find(&mut parents, m.first), find(&mut parents, m.second));
+        parents[a.max(b)] = a.min(b);
+    }
+
+    let mut clusters: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for m in matches.iter() {
+        for &i in &[m.first, m.second] {
+            let root = find(&mut parents, i);
+            clusters.entry(root).or_default().push(i);
+        }
+    }
+
+    clusters
+        .into_values()
+        .map(|mut cluster| {
+            cluster.sort_unstable();
+            cluster.dedup();
+            cluster
+        })
+        .collect()
+}
@@ -0,0 +1,27 @@
+use crate::Day02;
+use common::{Generator, Rng};
+
+const ID_LENGTH: usize = 26;
+
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 250;
+
+    // size box IDs of lowercase letters, two of which differ at exactly one position
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut ids: Vec<Vec<u8>> = (0..size.max(2) - 1).map(|_| random_id(rng)).collect();
+
+        let mut near = ids[0].clone();
+        let position = rng.index(ID_LENGTH);
+        near[position] = b'a' + (near[position] - b'a' + rng.range(1, 25) as u8) % 26;
+        ids.push(near);
+        rng.shuffle(&mut ids);
+
+        ids.into_iter()
+            .map(|id| String::from_utf8(id).unwrap() + "\n")
+            .collect()
+    }
+}
+
+fn random_id(rng: &mut Rng) -> Vec<u8> {
+    (0..ID_LENGTH).map(|_| b'a' + rng.below(26) as u8).collect()
+}
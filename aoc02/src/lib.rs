@@ -0,0 +1,334 @@
+use common::Solution;
+
+mod checksum;
+mod distance;
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
+pub use checksum::{histograms, Checksum, Combine, Histogram};
+pub use distance::{clusters, within, Match, Metric};
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(ids: &Self::Input) -> Result<Self::Part1> {
+        checksum(ids)
+    }
+
+    fn part2(ids: &Self::Input) -> Result<Self::Part2> {
+        near_duplicates(ids)
+            .into_iter()
+            .next()
+            .map(|pair| pair.common)
+            .ok_or_else(|| From::from("no two ids differ by a single letter"))
+    }
+}
+
+/// The puzzle's checksum, the number of ids with a character appearing exactly twice multiplied
+/// by the number with a character appearing exactly three times
+pub fn checksum<S: AsRef<str>>(ids: &[S]) -> Result<u64> {
+    Checksum::default().compute(ids)
+}
+
+/// The letters two ids have in common if they are the same length and differ in at most one
+/// position
+pub fn common_letters(id1: &str, id2: &str) -> Option<String> {
+    match Metric::Hamming.align(id1, id2)? {
+        (distance, common) if distance <= 1 => Some(common),
+        _ => None,
+    }
+}
+
+/// Two ids of the same length that differ at exactly one position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearDuplicate {
+    /// the index of the earlier id
+    pub first: usize,
+    /// the index of the later id
+    pub second: usize,
+    /// the position of the character that differs, counted in characters
+    pub position: usize,
+    /// the characters the ids have in common, i.e. either id without the differing character
+    pub common: String,
+}
+
+/// Finds every pair of ids that differ at exactly one position, ordered by the indices of the
+/// pair.
+///
+/// Rather than comparing every pair, for each position the ids are grouped by what's either side
+/// of that position. Two ids share a group exactly when they match everywhere but that position,
+/// and within a group only ids with different characters at the position are paired, so
+/// identical ids are never compared. The groups are keyed by rolling hashes of each id's
+/// prefixes and suffixes, which are worked out once per id, so the keys for every position take
+/// O(n·L) time between them.
+pub fn near_duplicates<S: AsRef<str>>(ids: &[S]) -> Vec<NearDuplicate> {
+    let ids: Vec<&str> = ids.iter().map(AsRef::as_ref).collect();
+    let chars: Vec<Vec<(usize, char)>> = ids.iter().map(|id| id.char_indices().collect()).collect();
+    let hashes: Vec<(Vec<u64>, Vec<u64>)> =
+        chars.iter().map(|chars| rolling_hashes(chars)).collect();
+    let longest = chars.iter().map(Vec::len).max().unwrap_or(0);
+    let mut pairs = Vec::new();
+
+    for position in 0..longest {
+        // (hash of the prefix, hash of the suffix, length, the masked character, index)
+        let mut masked: Vec<(u64, u64, usize, char, usize)> = chars
+            .iter()
+            .zip(&hashes)
+            .enumerate()
+            .filter(|(_, (chars, _))| position < chars.len())
+            .map(|(i, (chars, (prefixes, suffixes)))| {
+                let c = chars[position].1;
+                (
+                    prefixes[position],
+                    suffixes[position + 1],
+                    chars.len(),
+                    c,
+                    i,
+                )
+            })
+            .collect();
+        masked.sort_unstable();
+
+        for group in masked.chunk_by(|a, b| (a.0, a.1, a.2) == (b.0, b.1, b.2)) {
+            let buckets: Vec<_> = group.chunk_by(|a, b| a.3 == b.3).collect();
+
+            for (k, bucket) in buckets.iter().enumerate() {
+                for other in &buckets[k + 1..] {
+                    for &(.., a) in bucket.iter() {
+                        for &(.., b) in other.iter() {
+                            let (first, second) = (a.min(b), a.max(b));
+
+                            // the hashes can collide, so the pair is checked before it's kept
+                            if let Some(common) =
+                                masked_match(&ids, &chars, first, second, position)
+                            {
+                                pairs.push(NearDuplicate {
+                                    first,
+                                    second,
+                                    position,
+                                    common,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pairs.sort_unstable_by_key(|pair| (pair.first, pair.second));
+    pairs
+}
+
+// hashes are polynomials in BASE modulo the mersenne prime 2^61 - 1
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 1_000_003;
+
+// the hash of every prefix and of every suffix of the characters, by where they end and start
+fn rolling_hashes(chars: &[(usize, char)]) -> (Vec<u64>, Vec<u64>) {
+    let step = |hash: u64, c: char| {
+        ((u128::from(hash) * u128::from(BASE) + u128::from(c) + 1) % u128::from(MODULUS)) as u64
+    };
+
+    let mut prefixes = vec![0; chars.len() + 1];
+    let mut suffixes = vec![0; chars.len() + 1];
+    for (i, &(_, c)) in chars.iter().enumerate() {
+        prefixes[i + 1] = step(prefixes[i], c);
+    }
+    for (i, &(_, c)) in chars.iter().enumerate().rev() {
+        suffixes[i] = step(suffixes[i + 1], c);
+    }
+
+    (prefixes, suffixes)
+}
+
+// what the two ids have in common if they match everywhere but the position
+fn masked_match(
+    ids: &[&str],
+    chars: &[Vec<(usize, char)>],
+    first: usize,
+    second: usize,
+    position: usize,
+) -> Option<String> {
+    let (a, b) = (&chars[first], &chars[second]);
+    let same =
+        |x: &[(usize, char)], y: &[(usize, char)]| x.iter().map(|p| p.1).eq(y.iter().map(|p| p.1));
+
+    if a.len() != b.len()
+        || !same(&a[..position], &b[..position])
+        || !same(&a[position + 1..], &b[position + 1..])
+    {
+        return None;
+    }
+
+    let id = ids[first];
+    let (start, c) = a[position];
+    Some([&id[..start], &id[start + c.len_utf8()..]].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1() {
+        let ids = Day02::parse(include_str!("../input/example.txt")).unwrap();
+        assert_eq!(Day02::part1(&ids).unwrap(), 12);
+    }
+
+    #[test]
+    fn part2() {
+        let ids = Day02::parse(include_str!("../input/example2.txt")).unwrap();
+        assert_eq!(Day02::part2(&ids).unwrap(), "fgij");
+    }
+
+    #[test]
+    fn near_duplicates() {
+        let ids = ["abcd", "abce", "xbcd", "abcd", "abc", "äbce", "abcde"];
+        let pair = |first, second, position, common: &str| NearDuplicate {
+            first,
+            second,
+            position,
+            common: common.to_string(),
+        };
+
+        assert_eq!(
+            super::near_duplicates(&ids),
+            vec![
+                pair(0, 1, 3, "abc"),
+                pair(0, 2, 0, "bcd"),
+                pair(1, 3, 3, "abc"),
+                pair(1, 5, 0, "bce"),
+                pair(2, 3, 0, "bcd"),
+            ]
+        );
+    }
+
+    // identical ids land in the same group at every position, but are never compared
+    #[test]
+    fn many_duplicates() {
+        let mut ids = vec!["abcdefghijklmnopqrstuvwxyz"; 20_000];
+        assert!(super::near_duplicates(&ids).is_empty());
+
+        ids.push("abcdefghijklmnopqrstuvwxyy");
+        let pairs = super::near_duplicates(&ids);
+        assert_eq!(pairs.len(), 20_000);
+        assert!(pairs
+            .iter()
+            .all(|pair| pair.second == 20_000 && pair.position == 25));
+    }
+
+    #[test]
+    fn checksums() {
+        let ids = [
+            "abcdef",
+            "bababc",
+            "abbcde",
+            "abcccd",
+            "aabcdd",
+            "abcdee",
+            "ababab",
+            "éééxéé",
+        ];
+        let checksum = |repeats: &[usize], combine| Checksum::new(repeats, combine).compute(&ids);
+
+        assert_eq!(checksum(&[2, 3], Combine::Product).unwrap(), 12);
+        assert_eq!(checksum(&[2, 3], Combine::Sum).unwrap(), 7);
+        assert_eq!(checksum(&[5], Combine::Product).unwrap(), 1);
+        assert_eq!(checksum(&[], Combine::Sum).unwrap(), 0);
+
+        let histogram = &histograms(&ids)[1];
+        assert_eq!(histogram.to_string(), "a:2 b:3 c:1");
+        assert_eq!(histogram.count('b'), 3);
+        assert!(histogram.has_count(2) && !histogram.has_count(4));
+
+        // counts go well past what a byte can hold
+        let long = ["a".repeat(300)];
+        assert_eq!(Histogram::new(&long[0]).count('a'), 300);
+        assert_eq!(
+            Checksum::new(&[300], Combine::Sum).compute(&long).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn distances() {
+        assert_eq!(common_letters("abcde", "abxde"), Some("abde".to_string()));
+        assert_eq!(common_letters("abcde", "abcd"), None);
+        assert_eq!(Metric::Hamming.distance("abcd", "abc"), None);
+        assert_eq!(Metric::Hamming.distance("fghij", "fguij"), Some(1));
+
+        let aligned = Metric::Levenshtein.align("kitten", "sitting");
+        assert_eq!(aligned, Some((3, "ittn".to_string())));
+        assert_eq!(Metric::Levenshtein.distance("abcd", "abc"), Some(1));
+        assert_eq!(Metric::Levenshtein.distance("", "abc"), Some(3));
+    }
+
+    #[test]
+    fn queries() {
+        let ids = ["abcd", "abxd", "abc", "wxyz", "wxyy", "qqqq"];
+
+        let pairs: Vec<_> = within(&ids, Metric::Hamming, 1)
+            .into_iter()
+            .map(|m| (m.first, m.second, m.common))
+            .collect();
+        assert_eq!(
+            pairs,
+            vec![(0, 1, "abd".to_string()), (3, 4, "wxy".to_string())]
+        );
+
+        assert_eq!(
+            clusters(&ids, Metric::Levenshtein, 1),
+            vec![vec![0, 1, 2], vec![3, 4]]
+        );
+        assert_eq!(clusters(&ids, Metric::Hamming, 0), Vec::<Vec<usize>>::new());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 2..100usize) {
+            let (_, ids) = common::generated::<Day02>(seed, size);
+
+            prop_assert_eq!(Day02::part1(&ids).unwrap(), reference::checksum(&ids));
+            prop_assert_eq!(Day02::part2(&ids).ok(), reference::common_letters(&ids));
+        }
+
+        // a small alphabet so that plenty of ids are near duplicates of each other
+        #[test]
+        fn near_duplicates_match_reference(ids in prop::collection::vec("[abc]{0,4}", 0..40)) {
+            prop_assert_eq!(super::near_duplicates(&ids), reference::near_duplicates(&ids));
+        }
+
+        // the Hamming distance is one way of editing ids of the same length, so Levenshtein can
+        // never be further, and the common characters must appear in order in both ids
+        #[test]
+        fn levenshtein_bounds(a in "[abc]{0,6}", b in "[abc]{0,6}") {
+            let (distance, common) = Metric::Levenshtein.align(&a, &b).unwrap();
+            let is_subsequence = |id: &str| {
+                let mut chars = id.chars();
+                common.chars().all(|c| chars.any(|other| other == c))
+            };
+
+            prop_assert!(distance <= a.len().max(b.len()));
+            prop_assert!(common.len() + distance >= a.len().max(b.len()));
+            prop_assert!(is_subsequence(&a) && is_subsequence(&b));
+            if let Some(hamming) = Metric::Hamming.distance(&a, &b) {
+                prop_assert!(distance <= hamming);
+            }
+            prop_assert_eq!(Metric::Levenshtein.distance(&b, &a), Some(distance));
+        }
+    }
+}
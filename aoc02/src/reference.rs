@@ -0,0 +1,58 @@
+// letters counted one at a time, and every pair of ids compared character by character
+
+use crate::NearDuplicate;
+
+// counts each letter of each id separately
+pub fn checksum(ids: &[String]) -> u64 {
+    let has_count = |id: &String, n: usize| {
+        id.chars()
+            .any(|c| id.chars().filter(|&other| other == c).count() == n)
+    };
+    let twos = ids.iter().filter(|id| has_count(id, 2)).count() as u64;
+    let threes = ids.iter().filter(|id| has_count(id, 3)).count() as u64;
+
+    twos * threes
+}
+
+// compares every pair of ids, in the order they are given
+pub fn common_letters(ids: &[String]) -> Option<String> {
+    for (i, a) in ids.iter().enumerate() {
+        for b in &ids[i + 1..] {
+            let differing = a.chars().zip(b.chars()).filter(|(x, y)| x != y).count();
+
+            if differing == 1 {
+                let common = a.chars().zip(b.chars()).filter(|(x, y)| x == y);
+                return Some(common.map(|(x, _)| x).collect());
+            }
+        }
+    }
+
+    None
+}
+
+// compares every pair of ids character by character
+pub fn near_duplicates(ids: &[String]) -> Vec<NearDuplicate> {
+    let mut pairs = Vec::new();
+
+    for (first, a) in ids.iter().enumerate() {
+        for (second, b) in ids.iter().enumerate().skip(first + 1) {
+            let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+            let differing: Vec<usize> = (0..a.len()).filter(|&i| b.get(i) != Some(&a[i])).collect();
+
+            if a.len() == b.len() && differing.len() == 1 {
+                let position = differing[0];
+                let mut common = a.clone();
+                common.remove(position);
+
+                pairs.push(NearDuplicate {
+                    first,
+                    second,
+                    position,
+                    common: common.into_iter().collect(),
+                });
+            }
+        }
+    }
+
+    pairs
+}
@@ -0,0 +1,121 @@
+use crate::{sweep, Claim};
+use std::collections::HashMap;
+
+/// Another claim that a claim overlaps, and by how many square inches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    pub id: u32,
+    pub area: u64,
+}
+
+/// Which claims overlap which, for finding out who has to negotiate with who. Claims are
+/// identified by their ids, which are assumed to be unique.
+#[derive(Debug, Clone)]
+pub struct ConflictGraph {
+    ids: Vec<u32>,
+    index: HashMap<u32, usize>,
+    // the conflicts of each claim, in the same order as the claims and ordered by id
+    conflicts: Vec<Vec<Conflict>>,
+}
+
+impl ConflictGraph {
+    /// Finds every pair of overlapping claims, the same way as the sweep engine
+    pub fn new(claims: &[Claim]) -> Self {
+        let mut conflicts = vec![Vec::new(); claims.len()];
+
+        for (i, j, area) in sweep::overlapping_pairs(claims) {
+            conflicts[i].push(Conflict {
+                id: claims[j].id,
+                area,
+            });
+            conflicts[j].push(Conflict {
+                id: claims[i].id,
+                area,
+            });
+        }
+
+        for list in conflicts.iter_mut() {
+            list.sort_unstable_by_key(|conflict| conflict.id);
+        }
+
+        ConflictGraph {
+            ids: claims.iter().map(|claim| claim.id).collect(),
+            index: claims
+                .iter()
+                .enumerate()
+                .map(|(i, claim)| (claim.id, i))
+                .collect(),
+            conflicts,
+        }
+    }
+
+    /// The claims overlapping the claim with the given id, ordered by id, or None if there is no
+    /// such claim
+    pub fn conflicts(&self, id: u32) -> Option<&[Conflict]> {
+        self.index.get(&id).map(|&i| &self.conflicts[i][..])
+    }
+
+    /// Every pair of overlapping claims once each, as (lower id, higher id, overlapping area),
+    /// ordered by the ids
+    pub fn edges(&self) -> Vec<(u32, u32, u64)> {
+        let mut edges: Vec<(u32, u32, u64)> = self
+            .ids
+            .iter()
+            .zip(self.conflicts.iter())
+            .flat_map(|(&id, list)| {
+                list.iter()
+                    .filter(move |conflict| id < conflict.id)
+                    .map(move |conflict| (id, conflict.id, conflict.area))
+            })
+            .collect();
+
+        edges.sort_unstable();
+        edges
+    }
+
+    /// The ids of the claims that overlap no other claim, in the order they were given
+    pub fn uncontested(&self) -> Vec<u32> {
+        self.ids
+            .iter()
+            .zip(self.conflicts.iter())
+            .filter(|(_, list)| list.is_empty())
+            .map(|(&id, _)| id)
+            .collect()
+    }
+
+    /// Groups of claims linked by overlaps, where each claim overlaps at least one other in its
+    /// group and none outside it. Each group is ordered by id and the groups by their lowest id,
+    /// uncontested claims are left out.
+    pub fn components(&self) -> Vec<Vec<u32>> {
+        let mut visited = vec![false; self.ids.len()];
+        let mut components = Vec::new();
+
+        for start in 0..self.ids.len() {
+            if visited[start] || self.conflicts[start].is_empty() {
+                continue;
+            }
+
+            visited[start] = true;
+            let mut stack = vec![start];
+            let mut component = Vec::new();
+
+            while let Some(i) = stack.pop() {
+                component.push(self.ids[i]);
+
+                for conflict in self.conflicts[i].iter() {
+                    let j = self.index[&conflict.id];
+                    if !visited[j] {
+                        visited[j] = true;
+                        stack.push(j);
+                    }
+                }
+            }
+
+            component.sort_unstable();
+            components.push(component);
+        }
+
+        components.sort_unstable();
+        components
+    }
+}
@@ -0,0 +1,31 @@
+use crate::Day03;
+use common::{Generator, Rng};
+
+// claims are kept within a 1000 inch square as in the puzzle, with the last strip reserved for
+// a single claim that nothing else can overlap
+const SIDE: i64 = 1000;
+const RESERVED: i64 = 100;
+
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 1300;
+
+    // size claims numbered in order, exactly one of which is guaranteed to be uncontested
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let uncontested = rng.index(size) + 1;
+
+        (1..=size)
+            .map(|id| {
+                let (width, height) = (rng.range(1, 29), rng.range(1, 29));
+                let x = if id == uncontested {
+                    rng.range(SIDE - RESERVED, SIDE - width)
+                } else {
+                    rng.range(0, SIDE - RESERVED - width)
+                };
+                let y = rng.range(0, SIDE - height);
+
+                format!("#{} @ {},{}: {}x{}\n", id, x, y, width, height)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+// renders the fabric as netpbm images, binary greymaps (P5) and pixmaps (P6), which most image
+// viewers open and which are simple enough to write without an image crate
+use crate::{Fabric, Result};
+
+// the colours of the pixmap
+const UNCLAIMED: [u8; 3] = [0, 0, 0];
+const CLAIMED: [u8; 3] = [90, 90, 90];
+const HIGHLIGHT: [u8; 3] = [0, 200, 80];
+
+impl Fabric {
+    /// A greymap with one pixel per square inch, brighter the more claims there are on it
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let max = self.max_count();
+        let mut image = self.header("P5");
+        image.extend(self.grid.iter().map(|bin| scale(bin.count, max, 0)));
+        image
+    }
+
+    /// A pixmap with one pixel per square inch, unclaimed inches are black, inches with a single
+    /// claim grey and overlapping inches red, brighter the more claims there are on them. The
+    /// inches of the highlighted claim, if there is one, are green, and it must be uncontested
+    /// so that no overlaps are hidden.
+    pub fn to_ppm(&self, highlight: Option<u32>) -> Result<Vec<u8>> {
+        let highlight = match highlight {
+            Some(id) => match self.claims.get(&id) {
+                Some((claim, _)) if self.claim_uncontested(claim) => Some(claim),
+                Some(_) => return Err(From::from(format!("claim #{} is contested", id))),
+                None => return Err(From::from(format!("claim #{} has not been made", id))),
+            },
+            None => None,
+        };
+
+        let max = self.max_count();
+        let mut pixels = vec![UNCLAIMED; self.grid.width() * self.grid.height()];
+
+        for (pixel, bin) in pixels.iter_mut().zip(self.grid.iter()) {
+            *pixel = match bin.count {
+                0 => UNCLAIMED,
+                1 => CLAIMED,
+                count => [scale(count, max, 128), 0, 0],
+            };
+        }
+
+        if let Some(claim) = highlight {
+            for (x, y) in claim.iter_points() {
+                pixels[y as usize * self.grid.width() + x as usize] = HIGHLIGHT;
+            }
+        }
+
+        let mut image = self.header("P6");
+        image.extend(pixels.iter().flatten());
+        Ok(image)
+    }
+
+    fn header(&self, magic: &str) -> Vec<u8> {
+        format!("{}\n{} {}\n255\n", magic, self.width(), self.height()).into_bytes()
+    }
+
+    fn max_count(&self) -> u32 {
+        self.grid.iter().map(|bin| bin.count).max().unwrap_or(0)
+    }
+}
+
+// spreads counts up to max over the levels from floor to 255, 0 stays at 0
+fn scale(count: u32, max: u32, floor: u8) -> u8 {
+    if count == 0 {
+        return 0;
+    }
+
+    let span = u64::from(255 - floor);
+    (u64::from(floor) + u64::from(count) * span / u64::from(max)) as u8
+}
@@ -1,51 +1,325 @@
+use common::{ErrorKind, Grid, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::convert::TryFrom;
+use std::ops::Range;
+
+mod conflicts;
+mod generate;
+mod image;
+pub mod sweep;
+
+#[cfg(test)]
+mod reference;
+
+pub use conflicts::{Conflict, ConflictGraph};
 
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-// a square piece of fabric, each u8 corresponds to a 1x1 bin, with the bins covering the fabric
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Claim>;
+    type Part1 = u64;
+    type Part2 = u32;
+
+    // claims are told apart by their ids, so each id may only be used once
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut seen: HashMap<u32, usize> = HashMap::new();
+
+        Ok(common::parse_lines(input, |line| {
+            let (claim, id_columns) = parse_claim(line)?;
+
+            if let Some(first) = seen.insert(claim.id, seen.len() + 1) {
+                return Err(ParseError::new(
+                    ErrorKind::InvalidValue,
+                    line,
+                    id_columns,
+                    format!("a claim id not already used on line {}", first),
+                ));
+            }
+
+            Ok(claim)
+        })?)
+    }
+
+    fn part1(claims: &Self::Input) -> Result<Self::Part1> {
+        Engine::for_claims(claims).overlapping_area(claims)
+    }
+
+    fn part2(claims: &Self::Input) -> Result<Self::Part2> {
+        let uncontested = Engine::for_claims(claims).uncontested(claims)?;
+
+        uncontested
+            .first()
+            .copied()
+            .ok_or_else(|| From::from("every claim overlaps another"))
+    }
+}
+
+/// How the overlaps between claims are worked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Engine {
+    /// counts the claims on every square inch of a Fabric
+    Grid,
+    /// sweeps across the edges of the claims without counting each square inch, for claims
+    /// spread across huge pieces of fabric
+    Sweep,
+}
+
+// the largest fabric, in square inches, that claims are made on
+const GRID_LIMIT: u64 = 16_000_000;
+
+// the width and height of the smallest fabric holding every claim
+fn extents(claims: &[Claim]) -> (u64, u64) {
+    let width = claims.iter().map(Claim::right).max().unwrap_or(0);
+    let height = claims.iter().map(Claim::bottom).max().unwrap_or(0);
+    (width, height)
+}
+
+// whether a fabric of this size is small enough to count every square inch of, the grid is
+// also indexed by i32
+fn fits_grid(width: u64, height: u64) -> bool {
+    width <= i32::MAX as u64
+        && height <= i32::MAX as u64
+        && width
+            .checked_mul(height)
+            .is_some_and(|area| area <= GRID_LIMIT)
+}
+
+impl Engine {
+    /// The grid engine if the claims fit on a reasonably sized fabric, otherwise the sweep engine
+    pub fn for_claims(claims: &[Claim]) -> Self {
+        let (width, height) = extents(claims);
+
+        if fits_grid(width, height) {
+            Engine::Grid
+        } else {
+            Engine::Sweep
+        }
+    }
+
+    /// The area covered by two or more claims, which must have unique ids
+    pub fn overlapping_area(self, claims: &[Claim]) -> Result<u64> {
+        unique_ids(claims)?;
+
+        match self {
+            Engine::Grid => Ok(Fabric::with_claims(claims)?.count_overlapping()),
+            Engine::Sweep => Ok(sweep::overlapping_area(claims)),
+        }
+    }
+
+    /// The ids of the claims that share no area with any other claim, in the order they are
+    /// given. The claims must have unique ids.
+    pub fn uncontested(self, claims: &[Claim]) -> Result<Vec<u32>> {
+        unique_ids(claims)?;
+
+        match self {
+            Engine::Grid => {
+                let fabric = Fabric::with_claims(claims)?;
+
+                Ok(claims
+                    .iter()
+                    .filter(|claim| fabric.claim_uncontested(claim))
+                    .map(|claim| claim.id)
+                    .collect())
+            }
+            Engine::Sweep => Ok(sweep::uncontested(claims)),
+        }
+    }
+}
+
+// the fabric tells claims apart by id, so both engines insist on unique ids to agree
+fn unique_ids(claims: &[Claim]) -> Result<()> {
+    let mut ids = HashSet::with_capacity(claims.len());
+
+    match claims.iter().find(|claim| !ids.insert(claim.id)) {
+        Some(claim) => Err(From::from(format!("claim #{} is made twice", claim.id))),
+        None => Ok(()),
+    }
+}
+
+// a rectangular piece of fabric divided into 1x1 bins. Alongside the count of claims on each bin
+// the overlapping bins, and how many of each claim's bins are shared, are kept up to date as
+// claims are made and removed so that neither question needs the whole grid scanning.
 pub struct Fabric {
-    grid: Vec<u8>,
-    side: u32,
+    grid: Grid<Bin>,
+    // the claims on the fabric by id, and how many of each claim's bins have other claims on them
+    claims: HashMap<u32, (Claim, u64)>,
+    overlapping: u64,
+    uncontested: BTreeSet<u32>,
+}
+
+// the claims on a bin. Rather than listing them, the ids are xor'd together, which gives the id
+// of the only claim left on the bin whenever there is just one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bin {
+    pub count: u32,
+    ids: u32,
 }
 
 impl Fabric {
-    pub fn with_side(side: u32) -> Self {
-        let nbins = side * side;
+    pub fn with_side(side: u32) -> Result<Self> {
+        Fabric::with_size(side, side)
+    }
+
+    /// An empty fabric, as long as it's no more than 16 million square inches
+    pub fn with_size(width: u32, height: u32) -> Result<Self> {
+        if !fits_grid(u64::from(width), u64::from(height)) {
+            return Err(From::from(format!(
+                "a {}x{} fabric is too large to count each square inch of",
+                width, height
+            )));
+        }
+
+        Ok(Fabric::blank(width, height))
+    }
 
+    fn blank(width: u32, height: u32) -> Self {
         Fabric {
-            grid: vec![0; nbins as usize],
-            side,
+            grid: Grid::new(width as usize, height as usize, Bin::default()),
+            claims: HashMap::new(),
+            overlapping: 0,
+            uncontested: BTreeSet::new(),
         }
     }
 
-    // increments every bin in the grid that the claim claims by 1
-    pub fn make_claim(&mut self, claim: &Claim) {
-        for point in claim.iter_points() {
-            let pos = point.0 + self.side * point.1;
-            self.grid[pos as usize] += 1;
+    /// A fabric just big enough to hold every claim, with every claim made on it. Claims
+    /// spread over more than 16 million square inches are rejected, the sweep engine handles
+    /// those.
+    pub fn with_claims(claims: &[Claim]) -> Result<Self> {
+        let (width, height) = extents(claims);
+
+        if !fits_grid(width, height) {
+            return Err(From::from(format!(
+                "claims cover {}x{} inches, too large for a fabric",
+                width, height
+            )));
+        }
+
+        let mut fabric = Fabric::blank(width as u32, height as u32);
+
+        for claim in claims {
+            fabric.make_claim(claim)?;
         }
+
+        Ok(fabric)
     }
 
-    // counts the number of bins that have 2 or more claims
-    pub fn count_overlapping(&self) -> u32 {
-        self.grid.iter().filter(|&count| *count > 1).count() as u32
+    pub fn width(&self) -> u32 {
+        self.grid.width() as u32
     }
 
-    pub fn claim_uncontested(&self, claim: &Claim) -> bool {
-        for point in claim.iter_points() {
-            let pos = point.0 + self.side * point.1;
-            if self.grid[pos as usize] != 1 {
-                return false;
+    pub fn height(&self) -> u32 {
+        self.grid.height() as u32
+    }
+
+    pub fn bin(&self, x: u32, y: u32) -> Option<&Bin> {
+        self.grid
+            .get((i32::try_from(x).ok()?, i32::try_from(y).ok()?))
+    }
+
+    // adds the claim to every bin it covers, claims that don't fit on the fabric or share an id
+    // with a claim already made are rejected without claiming anything
+    pub fn make_claim(&mut self, claim: &Claim) -> Result<()> {
+        if claim.right() > u64::from(self.width()) || claim.bottom() > u64::from(self.height()) {
+            return Err(From::from(format!(
+                "claim #{} extends past the {}x{} fabric",
+                claim.id,
+                self.width(),
+                self.height()
+            )));
+        }
+
+        if self.claims.contains_key(&claim.id) {
+            return Err(From::from(format!(
+                "claim #{} has already been made",
+                claim.id
+            )));
+        }
+
+        let mut shared = 0;
+
+        for (x, y) in claim.iter_points() {
+            let bin = &mut self.grid[(x as i32, y as i32)];
+            let before = *bin;
+            bin.count += 1;
+            bin.ids ^= claim.id;
+
+            match bin.count {
+                1 => {}
+                // the claim that had the bin to itself now shares it
+                2 => {
+                    self.overlapping += 1;
+                    shared += 1;
+                    self.share(before.ids, 1);
+                }
+                _ => shared += 1,
             }
         }
 
-        true
+        self.claims.insert(claim.id, (claim.clone(), shared));
+        if shared == 0 {
+            self.uncontested.insert(claim.id);
+        }
+
+        Ok(())
+    }
+
+    /// Takes the claim with the given id back off the fabric, returning it
+    pub fn remove_claim(&mut self, id: u32) -> Result<Claim> {
+        let (claim, _) = self
+            .claims
+            .remove(&id)
+            .ok_or_else(|| format!("claim #{} has not been made", id))?;
+        self.uncontested.remove(&id);
+
+        for (x, y) in claim.iter_points() {
+            let bin = &mut self.grid[(x as i32, y as i32)];
+            bin.count -= 1;
+            bin.ids ^= claim.id;
+
+            // the claim left behind has the bin to itself again
+            if bin.count == 1 {
+                let remaining = bin.ids;
+                self.overlapping -= 1;
+                self.share(remaining, -1);
+            }
+        }
+
+        Ok(claim)
+    }
+
+    // changes how many of a claim's bins are shared, keeping track of whether it's uncontested
+    fn share(&mut self, id: u32, change: i64) {
+        let shared = &mut self.claims.get_mut(&id).unwrap().1;
+        *shared = (*shared as i64 + change) as u64;
+
+        if *shared == 0 {
+            self.uncontested.insert(id);
+        } else {
+            self.uncontested.remove(&id);
+        }
+    }
+
+    // the number of bins that have 2 or more claims
+    pub fn count_overlapping(&self) -> u64 {
+        self.overlapping
+    }
+
+    // a claim is uncontested if it has been made and is the only claim on each of its bins
+    pub fn claim_uncontested(&self, claim: &Claim) -> bool {
+        self.uncontested.contains(&claim.id)
+    }
+
+    /// The ids of every uncontested claim on the fabric, in ascending order
+    pub fn uncontested(&self) -> impl Iterator<Item = u32> + '_ {
+        self.uncontested.iter().copied()
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Claim {
     pub id: u32,
     pub x: u32,
@@ -55,13 +329,19 @@ pub struct Claim {
 }
 
 impl TryFrom<&str> for Claim {
-    type Error = Box<dyn std::error::Error>;
+    type Error = ParseError;
 
-    fn try_from(input: &str) -> Result<Self> {
-        // input of form #1 @ 45,64: 22x22
-        lazy_static! {
-            static ref RE: Regex = Regex::new(
-                r"(?x)
+    fn try_from(input: &str) -> std::result::Result<Self, ParseError> {
+        parse_claim(input).map(|(claim, _)| claim)
+    }
+}
+
+// the claim along with the columns holding its id, for pointing at it if it's used twice
+fn parse_claim(input: &str) -> std::result::Result<(Claim, Range<usize>), ParseError> {
+    // input of form #1 @ 45,64: 22x22
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"(?x)
                 \#
                 (?P<id>[0-9]+)
                 \s+@\s+
@@ -69,27 +349,60 @@ impl TryFrom<&str> for Claim {
                 \s+
                 (?P<width>[0-9]+)x(?P<height>[0-9]+)
                 "
-            )
-            .unwrap();
+        )
+        .unwrap();
+    }
+
+    let caps = match RE.captures(input) {
+        Some(caps) => caps,
+        None => {
+            return Err(ParseError::unrecognized(
+                input,
+                "a claim of the form #<id> @ <x>,<y>: <width>x<height>",
+            ))
         }
+    };
+    let field = |name: &str, expected: &str| {
+        common::number(input, caps.name(name).unwrap().range(), expected)
+    };
 
-        let caps = match RE.captures(input) {
-            Some(caps) => caps,
-            None => return Err(From::from("unrecognized format for claim")),
-        };
+    let claim = Claim {
+        id: field("id", "a claim id")?,
+        x: field("x", "an x offset")?,
+        y: field("y", "a y offset")?,
+        width: field("width", "a width")?,
+        height: field("height", "a height")?,
+    };
 
-        Ok(Claim {
-            id: caps["id"].parse()?,
-            x: caps["x"].parse()?,
-            y: caps["y"].parse()?,
-            width: caps["width"].parse()?,
-            height: caps["height"].parse()?,
-        })
-    }
+    Ok((claim, caps.name("id").unwrap().range()))
 }
 
 impl Claim {
-    pub fn iter_points(&self) -> IterPoints {
+    /// One past the rightmost column of the claim
+    pub fn right(&self) -> u64 {
+        u64::from(self.x) + u64::from(self.width)
+    }
+
+    /// One past the bottom row of the claim
+    pub fn bottom(&self) -> u64 {
+        u64::from(self.y) + u64::from(self.height)
+    }
+
+    /// The number of square inches this claim shares with another
+    pub fn overlap_area(&self, other: &Claim) -> u64 {
+        let width = self
+            .right()
+            .min(other.right())
+            .saturating_sub(u64::from(self.x.max(other.x)));
+        let height = self
+            .bottom()
+            .min(other.bottom())
+            .saturating_sub(u64::from(self.y.max(other.y)));
+
+        width * height
+    }
+
+    pub fn iter_points(&self) -> IterPoints<'_> {
         IterPoints {
             claim: self,
             px: self.x,
@@ -123,3 +436,232 @@ impl<'c> Iterator for IterPoints<'c> {
         Some((px, py))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::convert::TryInto;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let claims = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part1(&claims).unwrap(), 4);
+    }
+
+    #[test]
+    fn part2() {
+        let claims = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part2(&claims).unwrap(), 3);
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        // the id is found by the pattern rather than the first # on the line
+        let error = Day03::parse("#12 @ 0,0: 1x1\nnote # #12 @ 3,3: 1x1").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.text(), "12");
+        assert_eq!(error.columns, 8..10);
+
+        // rejected however far apart the claims are, so the choice of engine can't matter
+        for input in [
+            "#1 @ 0,0: 2x2\n#1 @ 5,5: 2x2",
+            "#1 @ 0,0: 2x2\n#1 @ 5000,5000: 2x2",
+        ] {
+            let error = Day03::parse(input).unwrap_err().to_string();
+            assert!(error.contains("line 2"), "{}", error);
+            assert!(
+                error.contains("a claim id not already used on line 1"),
+                "{}",
+                error
+            );
+        }
+
+        let claims: Vec<Claim> = vec![
+            "#1 @ 0,0: 2x2".try_into().unwrap(),
+            "#1 @ 5000,5000: 2x2".try_into().unwrap(),
+        ];
+        for engine in IntoIterator::into_iter([Engine::Grid, Engine::Sweep]) {
+            assert!(engine.overlapping_area(&claims).is_err());
+            assert!(engine.uncontested(&claims).is_err());
+        }
+    }
+
+    #[test]
+    fn fabric() {
+        let claims = Day03::parse("#1 @ 2000,10: 5x5\n#2 @ 2003,12: 4x4").unwrap();
+        let fabric = Fabric::with_claims(&claims).unwrap();
+        assert_eq!((fabric.width(), fabric.height()), (2007, 16));
+        assert_eq!(fabric.count_overlapping(), 6);
+
+        let mut fabric = Fabric::with_side(1000).unwrap();
+        assert!(fabric.make_claim(&claims[0]).is_err());
+        assert!(!fabric.claim_uncontested(&claims[0]));
+
+        // more claims on one bin than a byte can count
+        let piled: Vec<Claim> = (1..=300)
+            .map(|id| format!("#{} @ 0,0: 1x1", id).as_str().try_into().unwrap())
+            .collect();
+        let fabric = Fabric::with_claims(&piled).unwrap();
+        assert_eq!(fabric.count_overlapping(), 1);
+        assert_eq!(fabric.bin(0, 0).unwrap().count, 300);
+
+        let huge: Claim = "#1 @ 4294967000,0: 1000x1".try_into().unwrap();
+        assert!(Fabric::with_claims(&[huge]).is_err());
+
+        // too much fabric to count each square inch of, rather than too wide to index
+        let claims = Day03::parse("#1 @ 0,0: 200000x200000").unwrap();
+        assert!(Fabric::with_claims(&claims).is_err());
+        assert!(Fabric::with_side(100_000).is_err());
+        assert!(Fabric::with_size(u32::MAX, 1).is_err());
+        assert_eq!(Fabric::with_side(10).unwrap().bin(u32::MAX, 0), None);
+        assert_eq!(Day03::part1(&claims).unwrap(), 0);
+        assert_eq!(Day03::part2(&claims).unwrap(), 1);
+    }
+
+    #[test]
+    fn remove_claims() {
+        let claims = Day03::parse(
+            "#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 4,4: 1x1
+#4 @ 6,6: 1x1",
+        )
+        .unwrap();
+        let mut fabric = Fabric::with_claims(&claims).unwrap();
+        assert_eq!(fabric.count_overlapping(), 4);
+        assert_eq!(fabric.uncontested().collect::<Vec<_>>(), vec![4]);
+        assert!(fabric.make_claim(&claims[0]).is_err());
+
+        assert_eq!(fabric.remove_claim(3).unwrap(), claims[2]);
+        assert_eq!(fabric.count_overlapping(), 4);
+        assert!(fabric.remove_claim(3).is_err());
+
+        fabric.remove_claim(2).unwrap();
+        assert_eq!(fabric.count_overlapping(), 0);
+        assert_eq!(fabric.uncontested().collect::<Vec<_>>(), vec![1, 4]);
+
+        fabric.make_claim(&claims[2]).unwrap();
+        assert_eq!(fabric.count_overlapping(), 1);
+        assert!(!fabric.claim_uncontested(&claims[0]));
+        assert_eq!(
+            fabric.bin(4, 4),
+            Some(&Bin {
+                count: 2,
+                ids: 1 ^ 3
+            })
+        );
+    }
+
+    #[test]
+    fn images() {
+        let claims = Day03::parse(
+            "#1 @ 0,0: 2x1
+#2 @ 1,0: 2x1
+#3 @ 0,1: 1x1",
+        )
+        .unwrap();
+        let fabric = Fabric::with_claims(&claims).unwrap();
+
+        assert_eq!(
+            fabric.to_pgm(),
+            b"P5\n3 2\n255\n\x7f\xff\x7f\x7f\0\0".to_vec()
+        );
+
+        let ppm = fabric.to_ppm(Some(3)).unwrap();
+        let (header, pixels) = ppm.split_at(11);
+        assert_eq!(header, b"P6\n3 2\n255\n");
+        assert_eq!(
+            pixels.chunks(3).collect::<Vec<_>>(),
+            vec![
+                &[90, 90, 90][..],
+                &[255, 0, 0],
+                &[90, 90, 90],
+                &[0, 200, 80],
+                &[0, 0, 0],
+                &[0, 0, 0]
+            ]
+        );
+        assert!(fabric.to_ppm(Some(4)).is_err());
+        assert!(fabric.to_ppm(Some(1)).is_err());
+    }
+
+    #[test]
+    fn sweep() {
+        let claims = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Engine::Sweep.overlapping_area(&claims).unwrap(), 4);
+        assert_eq!(Engine::Sweep.uncontested(&claims).unwrap(), vec![3]);
+
+        // far too big to count inch by inch
+        let claims = Day03::parse(
+            "#1 @ 1000000,2000000: 3000000x3000000
+#2 @ 3000000,4000000: 2000000x2000000
+#3 @ 0,0: 0x10
+#4 @ 9000000,9000000: 10x10",
+        )
+        .unwrap();
+        assert_eq!(Engine::for_claims(&claims), Engine::Sweep);
+        assert_eq!(Day03::part1(&claims).unwrap(), 1_000_000 * 1_000_000);
+        assert_eq!(Engine::Sweep.uncontested(&claims).unwrap(), vec![3, 4]);
+    }
+
+    #[test]
+    fn conflicts() {
+        let claims = Day03::parse(
+            "#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2
+#4 @ 6,6: 3x1
+#5 @ 20,20: 1x1",
+        )
+        .unwrap();
+        let graph = ConflictGraph::new(&claims);
+
+        assert_eq!(graph.conflicts(1), Some(&[Conflict { id: 2, area: 4 }][..]));
+        assert_eq!(graph.conflicts(3).unwrap().len(), 1);
+        assert_eq!(graph.conflicts(9), None);
+        assert_eq!(graph.edges(), vec![(1, 2, 4), (3, 4, 1)]);
+        assert_eq!(graph.uncontested(), vec![5]);
+        assert_eq!(graph.components(), vec![vec![1, 2], vec![3, 4]]);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..300usize) {
+            let (_, claims) = common::generated::<Day03>(seed, size);
+
+            prop_assert_eq!(Day03::part1(&claims).unwrap(), reference::count_overlapping(&claims));
+            prop_assert_eq!(Day03::part2(&claims).ok(), reference::uncontested(&claims));
+
+            let graph = ConflictGraph::new(&claims);
+            prop_assert_eq!(graph.uncontested().first().copied(), reference::uncontested(&claims));
+
+            // taking half of the claims back off leaves the same fabric as never making them
+            let mut fabric = Fabric::with_claims(&claims).unwrap();
+            let (kept, removed) = claims.split_at(claims.len() / 2);
+            for claim in removed {
+                fabric.remove_claim(claim.id).unwrap();
+            }
+            prop_assert_eq!(fabric.count_overlapping(), reference::count_overlapping(kept));
+            prop_assert_eq!(
+                kept.iter().find(|claim| fabric.claim_uncontested(claim)).map(|claim| claim.id),
+                reference::uncontested(kept)
+            );
+
+            for engine in IntoIterator::into_iter([Engine::Grid, Engine::Sweep]) {
+                prop_assert_eq!(
+                    engine.overlapping_area(&claims).unwrap(),
+                    reference::count_overlapping(&claims)
+                );
+                prop_assert_eq!(
+                    engine.uncontested(&claims).unwrap().first().copied(),
+                    reference::uncontested(&claims)
+                );
+            }
+        }
+    }
+}
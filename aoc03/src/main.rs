@@ -1,32 +1,24 @@
-use aoc03::{Claim, Fabric, Result};
-use std::convert::TryInto;
+use aoc03::{Day03, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-fn main() -> Result<()> {
-    let mut input = String::new();
-    io::stdin().read_to_string(&mut input)?;
-
-    let mut claims: Vec<Claim> = Vec::new();
-
-    for line in input.lines() {
-        // this should probably be turned into FromStr and line.parse()
-        let claim: Claim = line.try_into()?;
-        claims.push(claim);
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
     }
+}
 
-    let mut fabric = Fabric::with_side(1000);
+fn run() -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
 
-    for claim in claims.iter() {
-        fabric.make_claim(claim);
-    }
+    let claims = Day03::parse(&input)?;
 
-    println!("{}", fabric.count_overlapping());
+    println!("{}", Day03::part1(&claims)?);
 
-    for claim in claims.iter() {
-        if fabric.claim_uncontested(claim) {
-            println!("{}", claim.id);
-        }
-    }
+    println!("{}", Day03::part2(&claims)?);
 
     Ok(())
 }
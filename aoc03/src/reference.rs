@@ -0,0 +1,37 @@
+// square inches tallied in a hash map rather than a grid, claims intersected pairwise
+
+use crate::Claim;
+use std::collections::HashMap;
+
+// tallies every square inch of every claim in a map
+pub fn count_overlapping(claims: &[Claim]) -> u64 {
+    let mut tally: HashMap<(u32, u32), u32> = HashMap::new();
+
+    for claim in claims {
+        for x in claim.x..claim.x + claim.width {
+            for y in claim.y..claim.y + claim.height {
+                *tally.entry((x, y)).or_default() += 1;
+            }
+        }
+    }
+
+    tally.values().filter(|&&count| count > 1).count() as u64
+}
+
+// the first claim whose rectangle intersects no other claim's rectangle
+pub fn uncontested(claims: &[Claim]) -> Option<u32> {
+    let intersect = |a: &Claim, b: &Claim| {
+        a.x < b.x + b.width && b.x < a.x + a.width && a.y < b.y + b.height && b.y < a.y + a.height
+    };
+
+    claims
+        .iter()
+        .enumerate()
+        .find(|&(i, a)| {
+            claims
+                .iter()
+                .enumerate()
+                .all(|(j, b)| i == j || !intersect(a, b))
+        })
+        .map(|(_, claim)| claim.id)
+}
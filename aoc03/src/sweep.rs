@@ -0,0 +1,101 @@
+use crate::Claim;
+
+// the x coordinates where any claim starts or ends, sorted and without duplicates
+fn columns(claims: &[Claim]) -> Vec<u64> {
+    let mut xs: Vec<u64> = claims
+        .iter()
+        .flat_map(|claim| vec![u64::from(claim.x), claim.right()])
+        .collect();
+    xs.sort_unstable();
+    xs.dedup();
+    xs
+}
+
+/// The area covered by two or more claims.
+///
+/// The x coordinates of the claims' edges are compressed so that the fabric is split into
+/// columns between consecutive edges. A line is then swept down the fabric stopping at the top
+/// and bottom edge of each claim, keeping count of the claims covering each column, and the
+/// width of the columns covered twice or more is added up for each stretch between stops. This
+/// takes O(n²) time in the number of claims regardless of how large they are.
+pub fn overlapping_area(claims: &[Claim]) -> u64 {
+    let xs = columns(claims);
+    let column = |x: u64| xs.binary_search(&x).unwrap();
+
+    // (y, change in coverage, first column, one past the last column)
+    let mut edges: Vec<(u64, i64, usize, usize)> = Vec::with_capacity(claims.len() * 2);
+    for claim in claims
+        .iter()
+        .filter(|claim| claim.width > 0 && claim.height > 0)
+    {
+        let (start, end) = (column(u64::from(claim.x)), column(claim.right()));
+        edges.push((u64::from(claim.y), 1, start, end));
+        edges.push((claim.bottom(), -1, start, end));
+    }
+    edges.sort_unstable();
+
+    let mut coverage = vec![0i64; xs.len().saturating_sub(1)];
+    let mut area = 0;
+    let mut last_y = 0;
+
+    for (y, change, start, end) in edges {
+        let overlapped: u64 = coverage
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 1)
+            .map(|(i, _)| xs[i + 1] - xs[i])
+            .sum();
+        area += overlapped * (y - last_y);
+        last_y = y;
+
+        for count in &mut coverage[start..end] {
+            *count += change;
+        }
+    }
+
+    area
+}
+
+/// Every pair of overlapping claims, as the indices of the two claims and the area they share.
+///
+/// The claims are sorted by their left edge, so each claim only needs comparing with those that
+/// start before it ends.
+pub(crate) fn overlapping_pairs(claims: &[Claim]) -> Vec<(usize, usize, u64)> {
+    let mut order: Vec<usize> = (0..claims.len()).collect();
+    order.sort_unstable_by_key(|&i| claims[i].x);
+
+    let mut pairs = Vec::new();
+
+    for (k, &i) in order.iter().enumerate() {
+        let a = &claims[i];
+
+        for &j in order[k + 1..]
+            .iter()
+            .take_while(|&&j| u64::from(claims[j].x) < a.right())
+        {
+            let area = a.overlap_area(&claims[j]);
+            if area > 0 {
+                pairs.push((i, j, area));
+            }
+        }
+    }
+
+    pairs
+}
+
+/// The ids of the claims that share no area with any other claim, in the order they are given
+pub fn uncontested(claims: &[Claim]) -> Vec<u32> {
+    let mut contested = vec![false; claims.len()];
+
+    for (i, j, _) in overlapping_pairs(claims) {
+        contested[i] = true;
+        contested[j] = true;
+    }
+
+    claims
+        .iter()
+        .zip(contested)
+        .filter(|&(_, contested)| !contested)
+        .map(|(claim, _)| claim.id)
+        .collect()
+}
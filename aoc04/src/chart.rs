@@ -0,0 +1,55 @@
+use crate::{DateTime, LogsByGuard, ID};
+use std::ops::RangeBounds;
+
+impl LogsByGuard {
+    /// The shifts drawn as in the puzzle, a row for each night with a `#` for each minute of the
+    /// midnight hour the guard was asleep and a `.` for each they were awake. Only the guard's
+    /// shifts are drawn if one is given, and only the shifts on nights within the dates.
+    ///
+    /// ```text
+    /// Date   ID   Minute
+    ///             000000000011111111112222222222333333333344444444445555555555
+    ///             012345678901234567890123456789012345678901234567890123456789
+    /// 11-01  #10  .....####################.....#########################.....
+    /// ```
+    pub fn chart<R: RangeBounds<DateTime>>(&self, guard: Option<ID>, dates: R) -> String {
+        let shifts: Vec<_> = self
+            .shifts()
+            .iter()
+            .filter(|shift| guard.is_none_or(|id| shift.guard == id) && dates.contains(&shift.date))
+            .collect();
+
+        let ids: Vec<String> = shifts
+            .iter()
+            .map(|shift| format!("#{}", shift.guard))
+            .collect();
+        let width = ids.iter().map(String::len).max().unwrap_or(0).max(2);
+        let indent = " ".repeat(7 + width + 2);
+
+        let mut chart = format!("{:<7}{:<w$}Minute\n", "Date", "ID", w = width + 2);
+        chart += &indent;
+        chart.extend((0..60).map(|minute| char::from(b'0' + minute / 10)));
+        chart.push('\n');
+        chart += &indent;
+        chart.extend((0..60).map(|minute| char::from(b'0' + minute % 10)));
+        chart.push('\n');
+
+        for (shift, id) in shifts.iter().zip(&ids) {
+            let date = shift.date;
+            chart += &format!(
+                "{:02}-{:02}  {:<w$}  ",
+                date.month(),
+                date.day(),
+                id,
+                w = width
+            );
+            chart.extend((0..60).map(|minute| match date.add_minutes(minute) {
+                Some(at) if shift.asleep_at(at) => '#',
+                _ => '.',
+            }));
+            chart.push('\n');
+        }
+
+        chart
+    }
+}
@@ -0,0 +1,171 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+pub const MINUTES_PER_DAY: i64 = 24 * 60;
+
+// has format [1518-11-01 00:00], dates are in the proleptic gregorian calendar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime {
+    year: u32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+}
+
+impl DateTime {
+    /// The date and time, if the month has the day and the day has the hour and minute
+    pub fn new(year: u32, month: u32, day: u32, hour: u32, minute: u32) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+            || hour >= 24
+            || minute >= 60
+        {
+            return None;
+        }
+
+        Some(DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        })
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// The number of minutes from earlier to self, negative if earlier is later
+    pub fn minutes_since(&self, earlier: &DateTime) -> i64 {
+        self.minutes() - earlier.minutes()
+    }
+
+    /// The date and time the given number of minutes later, or earlier if negative, as long as
+    /// it's no earlier than year 0 and the year fits
+    pub fn add_minutes(&self, minutes: i64) -> Option<Self> {
+        DateTime::from_minutes(self.minutes().checked_add(minutes)?)
+    }
+
+    /// Midnight at the start of the day
+    pub fn start_of_day(&self) -> Self {
+        DateTime {
+            hour: 0,
+            minute: 0,
+            ..*self
+        }
+    }
+
+    /// Midnight at the start of the following day
+    pub fn start_of_next_day(&self) -> Option<Self> {
+        self.start_of_day().add_minutes(MINUTES_PER_DAY)
+    }
+
+    pub fn same_day(&self, other: &DateTime) -> bool {
+        self.start_of_day() == other.start_of_day()
+    }
+
+    /// Minutes since midnight
+    pub fn minute_of_day(&self) -> u32 {
+        self.hour * 60 + self.minute
+    }
+
+    // minutes since midnight at the start of year 0
+    pub(crate) fn minutes(&self) -> i64 {
+        let days = days_before_year(self.year)
+            + (1..self.month)
+                .map(|month| i64::from(days_in_month(self.year, month)))
+                .sum::<i64>()
+            + i64::from(self.day)
+            - 1;
+
+        days * MINUTES_PER_DAY + i64::from(self.minute_of_day())
+    }
+
+    fn from_minutes(minutes: i64) -> Option<Self> {
+        if minutes < 0 {
+            return None;
+        }
+
+        let mut days = minutes / MINUTES_PER_DAY;
+        let time = (minutes % MINUTES_PER_DAY) as u32;
+
+        // 146097 days in every 400 years, the estimate is then corrected by at most a year
+        let mut year = u32::try_from(days * 400 / 146_097).ok()?;
+        while days_before_year(year) > days {
+            year -= 1;
+        }
+        while days_before_year(year.checked_add(1)?) <= days {
+            year += 1;
+        }
+        days -= days_before_year(year);
+
+        let mut month = 1;
+        while days >= i64::from(days_in_month(year, month)) {
+            days -= i64::from(days_in_month(year, month));
+            month += 1;
+        }
+
+        Some(DateTime {
+            year,
+            month,
+            day: days as u32 + 1,
+            hour: time / 60,
+            minute: time % 60,
+        })
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+pub fn is_leap_year(year: u32) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// The number of days in the month, 0 if there's no such month
+pub fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => 0,
+    }
+}
+
+fn days_before_year(year: u32) -> i64 {
+    let past = i64::from(year);
+    // year 0 is a leap year
+    let leap = if year == 0 {
+        0
+    } else {
+        (past - 1) / 4 - (past - 1) / 100 + (past - 1) / 400 + 1
+    };
+
+    past * 365 + leap
+}
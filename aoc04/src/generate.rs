@@ -0,0 +1,49 @@
+use crate::{DateTime, Day04};
+use common::{Generator, Rng};
+
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 400;
+
+    // size shifts on consecutive nights from a pool of guards, with naps taken during the
+    // midnight hour. The events are shuffled, as in the puzzle input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let guards: Vec<u32> = (0..size / 15 + 1)
+            .map(|_| rng.range(1, 3500) as u32)
+            .collect();
+        let mut midnight = DateTime::new(1518, 1, 1, 0, 0).unwrap();
+        let mut lines = Vec::new();
+
+        for _ in 0..size.max(1) {
+            let previous = midnight;
+            midnight = midnight.start_of_next_day().unwrap();
+            let guard = rng.choose(&guards);
+
+            let start = if rng.chance(0.7) {
+                stamp(previous, 23 * 60 + rng.range(45, 59))
+            } else {
+                stamp(midnight, rng.range(0, 3))
+            };
+            lines.push(format!("{} Guard #{} begins shift", start, guard));
+
+            // pairs of distinct minutes, each pair being the minute a nap starts and ends
+            let naps = rng.range(0, 3) as usize;
+            let mut minutes: Vec<u32> = (5..60).collect();
+            rng.shuffle(&mut minutes);
+            minutes.truncate(naps * 2);
+            minutes.sort_unstable();
+
+            for nap in minutes.chunks(2) {
+                lines.push(format!("{} falls asleep", stamp(midnight, nap[0].into())));
+                lines.push(format!("{} wakes up", stamp(midnight, nap[1].into())));
+            }
+        }
+
+        rng.shuffle(&mut lines);
+
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+}
+
+fn stamp(midnight: DateTime, minutes: i64) -> String {
+    format!("[{}]", midnight.add_minutes(minutes).unwrap())
+}
@@ -1,18 +1,82 @@
+use common::{Answer, ErrorKind, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
+use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
 
+mod chart;
+mod datetime;
+mod generate;
+mod shift;
+mod stats;
+
+pub use datetime::{days_in_month, is_leap_year, DateTime, MINUTES_PER_DAY};
+pub use shift::{Nap, Shift};
+pub use stats::{GuardStats, Rank, SleepiestMinute};
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-// has format [1518-11-01 00:00]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct DateTime {
-    year: u32,
-    month: u32,
-    day: u32,
-    hour: u32,
-    minute: u32,
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = LogsByGuard;
+    type Part1 = Strategy;
+    type Part2 = Strategy;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        RawLogs::new(input)?.process_logs()
+    }
+
+    // strategy 1: the guard who sleeps the most and the minute they are most often asleep
+    fn part1(logs: &Self::Input) -> Result<Self::Part1> {
+        let guard = logs.sleeps_most().ok_or("no guard was ever asleep")?;
+        let sm = logs
+            .sleepiest_minute_by_guard(guard)
+            .ok_or("no logs for sleepiest guard")?;
+
+        Ok(Strategy {
+            guard,
+            minute: sm.minute,
+        })
+    }
+
+    // strategy 2: the guard who is most frequently asleep on the same minute
+    fn part2(logs: &Self::Input) -> Result<Self::Part2> {
+        let (guard, sm) = logs.sleepiest_minute().ok_or("no guard was ever asleep")?;
+
+        Ok(Strategy {
+            guard,
+            minute: sm.minute,
+        })
+    }
+}
+
+// the answer to either strategy is the chosen guard's ID multiplied by the chosen minute
+#[derive(Debug, PartialEq, Eq)]
+pub struct Strategy {
+    pub guard: ID,
+    pub minute: u32,
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.guard * self.minute)
+    }
+}
+
+impl Answer for Strategy {
+    fn value(&self) -> Value {
+        json!(self.guard * self.minute)
+    }
+
+    fn details(&self) -> Option<Value> {
+        Some(json!({ "guard": self.guard, "minute": self.minute }))
+    }
 }
 
 pub type ID = u32;
@@ -31,9 +95,9 @@ pub struct Event {
 }
 
 impl FromStr for Event {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
         // input of form
         // [1518-03-21 23:52] Guard #2887 begins shift
         // [1518-07-31 00:58] wakes up
@@ -57,27 +121,62 @@ impl FromStr for Event {
 
         let caps = match RE.captures(s) {
             Some(caps) => caps,
-            None => return Err(From::from("unrecognized format for event")),
+            None => {
+                return Err(ParseError::unrecognized(
+                    s,
+                    "an event of the form [YYYY-MM-DD hh:mm] <event>",
+                ))
+            }
+        };
+        let field = |name: &str, expected: &str| {
+            common::number(s, caps.name(name).unwrap().range(), expected)
         };
 
-        let datetime = DateTime {
-            year: caps["year"].parse()?,
-            month: caps["month"].parse()?,
-            day: caps["day"].parse()?,
-            hour: caps["hour"].parse()?,
-            minute: caps["minute"].parse()?,
+        let invalid = |name: &str, expected: String| {
+            ParseError::new(
+                ErrorKind::InvalidValue,
+                s,
+                caps.name(name).unwrap().range(),
+                expected,
+            )
         };
 
-        let kind = if let Some(id) = caps.name("id") {
+        let year = field("year", "a year")?;
+        let month = field("month", "a month")?;
+        let day = field("day", "a day")?;
+        let hour = field("hour", "an hour")?;
+        let minute = field("minute", "a minute")?;
+
+        // checked field by field so the error points at the one that's wrong
+        let datetime = match DateTime::new(year, month, day, hour, minute) {
+            Some(datetime) => datetime,
+            None if !(1..=12).contains(&month) => {
+                return Err(invalid("month", "a month from 01 to 12".into()))
+            }
+            None if hour >= 24 => return Err(invalid("hour", "an hour from 00 to 23".into())),
+            None if minute >= 60 => return Err(invalid("minute", "a minute from 00 to 59".into())),
+            None => {
+                let days = days_in_month(year, month);
+                let expected = format!("a day of {:04}-{:02}, from 01 to {}", year, month, days);
+                return Err(invalid("day", expected));
+            }
+        };
+
+        let kind = if caps.name("id").is_some() {
             EventKind::Start {
-                id: id.as_str().parse()?,
+                id: field("id", "a guard id")?,
             }
         } else if &caps["sleep"] == "wakes up" {
             EventKind::Wake
         } else if &caps["sleep"] == "falls asleep" {
             EventKind::Sleep
         } else {
-            return Err(From::from("unrecoginized event kind"));
+            return Err(ParseError::new(
+                ErrorKind::InvalidValue,
+                s,
+                caps.name("sleep").unwrap().range(),
+                "`Guard #<id> begins shift`, `wakes up` or `falls asleep`",
+            ));
         };
 
         Ok(Event { datetime, kind })
@@ -90,33 +189,28 @@ pub struct RawLogs {
 
 impl RawLogs {
     pub fn new(s: &str) -> Result<Self> {
-        let mut events: Vec<Event> = Vec::new();
+        let mut events: Vec<Event> = common::parse_lines(s, str::parse)?;
 
-        for line in s.lines() {
-            let event: Event = line.parse()?;
-            events.push(event);
-        }
-
-        events.sort_by(|c1, c2| c1.datetime.cmp(&c2.datetime));
+        events.sort_by_key(|event| event.datetime);
 
         Ok(RawLogs { events })
     }
 
-    pub fn process_logs(self) -> Result<LogsByGuard> {
+    /// Reconstructs each guard's shift from the events, in the order the shifts began
+    pub fn shifts(&self) -> Result<Vec<Shift>> {
         let mut events = self.events.iter();
-        let mut logs_by_guard: HashMap<ID, Vec<u32>> = HashMap::new();
+        let mut shifts = Vec::new();
 
-        let id = if let Some(event) = events.next() {
+        let mut shift = if let Some(event) = events.next() {
             match event.kind {
-                EventKind::Start { id } => id,
+                EventKind::Start { id } => Shift::new(id, event.datetime),
                 _ => return Err(From::from("invalid events log, no guard on duty")),
             }
         } else {
             return Err(From::from("Empty logs"));
         };
 
-        let mut log = logs_by_guard.entry(id).or_insert_with(|| vec![0; 60]);
-        let mut fell_asleep: Option<u32> = None;
+        let mut fell_asleep: Option<DateTime> = None;
 
         for event in events {
             match event.kind {
@@ -124,13 +218,16 @@ impl RawLogs {
                     if fell_asleep.is_some() {
                         return Err(From::from("Guard finished shift while asleep"));
                     }
-                    log = logs_by_guard.entry(id).or_insert_with(|| vec![0; 60]);
+                    let next = Shift::new(id, event.datetime);
+                    shift.end = next.start;
+                    shifts.push(std::mem::replace(&mut shift, next));
                 }
                 EventKind::Wake => {
-                    if let Some(fell_asleep) = fell_asleep.take() {
-                        for minute in fell_asleep..event.datetime.minute {
-                            log[minute as usize] += 1;
-                        }
+                    if let Some(start) = fell_asleep.take() {
+                        shift.naps.push(Nap {
+                            start,
+                            end: event.datetime,
+                        });
                     } else {
                         return Err(From::from("Can't wake twice in a row!"));
                     }
@@ -140,62 +237,391 @@ impl RawLogs {
                         return Err(From::from("Can't sleep twice in a row"));
                     }
 
-                    fell_asleep = Some(event.datetime.minute);
+                    fell_asleep = Some(event.datetime);
                 }
             }
         }
 
-        Ok(LogsByGuard {
-            inner: logs_by_guard,
-        })
+        // the logs can't end part way through a nap, any more than a shift can
+        if fell_asleep.is_some() {
+            return Err(From::from("Logs ended while guard asleep"));
+        }
+
+        let last = self
+            .events
+            .last()
+            .map_or(shift.start, |event| event.datetime);
+        shift.end = last.add_minutes(1).unwrap_or(last);
+        shifts.push(shift);
+
+        Ok(shifts)
     }
-}
 
-pub struct SleepiestMinute {
-    pub minute: u32,
-    pub frequency: u32,
+    pub fn process_logs(self) -> Result<LogsByGuard> {
+        Ok(LogsByGuard::new(self.shifts()?))
+    }
 }
 
 pub struct LogsByGuard {
+    // every shift, in the order they began
+    shifts: Vec<Shift>,
+    // how often each guard was asleep on each minute of the midnight hour, derived from the naps
     inner: HashMap<ID, Vec<u32>>,
 }
 
 impl LogsByGuard {
-    pub fn sleeps_most(&self) -> ID {
-        let (&id, _) = self
-            .inner
-            .iter()
-            .max_by_key(|(_, log)| log.iter().sum::<u32>())
-            .unwrap();
+    fn new(shifts: Vec<Shift>) -> Self {
+        let mut inner: HashMap<ID, Vec<u32>> = HashMap::new();
+
+        for shift in &shifts {
+            let log = inner.entry(shift.guard).or_insert_with(|| vec![0; 60]);
+            for minute in shift.naps.iter().flat_map(Nap::midnight_minutes) {
+                log[minute as usize] += 1;
+            }
+        }
+
+        LogsByGuard { shifts, inner }
+    }
 
-        id
+    pub fn shifts(&self) -> &[Shift] {
+        &self.shifts
     }
 
-    pub fn sleepiest_minute_by_guard(&self, id: ID) -> Option<SleepiestMinute> {
-        let log = self.inner.get(&id)?;
+    /// The guard's shifts, in the order they began
+    pub fn shifts_for(&self, id: ID) -> impl Iterator<Item = &Shift> {
+        self.shifts.iter().filter(move |shift| shift.guard == id)
+    }
 
-        log.iter()
-            .enumerate()
-            .max_by(|x, y| x.1.cmp(y.1))
-            .map(|(i, &f)| SleepiestMinute {
-                minute: i as u32,
-                frequency: f as u32,
-            })
+    /// Every nap the guard took, in the order they were taken
+    pub fn naps(&self, id: ID) -> impl Iterator<Item = &Nap> {
+        self.shifts_for(id).flat_map(|shift| shift.naps.iter())
     }
 
-    pub fn sleepiest_minute(&self) -> Option<(ID, SleepiestMinute)> {
-        self.inner.keys().fold(None, |acc, &cur_id| {
-            let cur_sm = self.sleepiest_minute_by_guard(cur_id)?;
+    /// The shift under way at the given time, if the logs cover it
+    pub fn shift_at(&self, at: DateTime) -> Option<&Shift> {
+        let started = self.shifts.partition_point(|shift| shift.start <= at);
+        let shift = &self.shifts[started.checked_sub(1)?];
 
-            if let Some((_, ref sm)) = acc {
-                if cur_sm.frequency > sm.frequency {
-                    return Some((cur_id, cur_sm));
-                }
+        if shift.on_duty(at) {
+            Some(shift)
+        } else {
+            None
+        }
+    }
+
+    pub fn on_duty(&self, at: DateTime) -> Option<ID> {
+        self.shift_at(at).map(|shift| shift.guard)
+    }
+
+    pub fn asleep(&self, id: ID, at: DateTime) -> bool {
+        self.shift_at(at)
+            .is_some_and(|shift| shift.guard == id && shift.asleep_at(at))
+    }
+}
 
-                acc
-            } else {
-                Some((cur_id, cur_sm))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::{any, prop_assert, prop_assert_eq, proptest};
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let logs = Day04::parse(EXAMPLE).unwrap();
+        let strategy = Day04::part1(&logs).unwrap();
+        assert_eq!(
+            strategy,
+            Strategy {
+                guard: 10,
+                minute: 24
             }
-        })
+        );
+        assert_eq!(strategy.to_string(), "240");
+        assert_eq!(strategy.value(), json!(240));
+        assert_eq!(
+            strategy.details(),
+            Some(json!({ "guard": 10, "minute": 24 }))
+        );
+    }
+
+    #[test]
+    fn part2() {
+        let logs = Day04::parse(EXAMPLE).unwrap();
+        let strategy = Day04::part2(&logs).unwrap();
+        assert_eq!(
+            strategy,
+            Strategy {
+                guard: 99,
+                minute: 45
+            }
+        );
+        assert_eq!(strategy.to_string(), "4455");
+    }
+
+    #[test]
+    fn long_naps() {
+        let logs = Day04::parse(
+            "[1518-02-28 23:50] Guard #1 begins shift
+[1518-02-28 23:58] falls asleep
+[1518-03-01 00:02] wakes up
+[1518-03-01 23:55] Guard #2 begins shift
+[1518-03-02 00:30] falls asleep
+[1518-03-02 02:10] wakes up
+[1518-12-31 23:00] Guard #1 begins shift
+[1518-12-31 23:30] falls asleep
+[1519-01-02 00:01] wakes up",
+        )
+        .unwrap();
+
+        let lengths: Vec<u64> = logs.naps(1).map(Nap::length).collect();
+        assert_eq!(lengths, vec![4, 24 * 60 + 31]);
+        assert_eq!(logs.naps(2).map(Nap::length).sum::<u64>(), 100);
+
+        // guard 1 slept through the midnight hour of 1519-01-01 and the first minute of the next
+        let log = &logs.inner[&1];
+        assert_eq!(log[0], 3);
+        assert_eq!(log[1], 2);
+        assert_eq!(log[2], 1);
+        assert_eq!(log[59], 1);
+        assert_eq!(logs.inner[&2][29..31], [0, 1]);
+        assert_eq!(logs.inner[&2][59], 1);
+    }
+
+    #[test]
+    fn asleep_at_end() {
+        let logs = "[1518-11-01 00:00] Guard #10 begins shift\n[1518-11-01 00:05] falls asleep";
+        assert!(Day04::parse(logs).is_err());
+    }
+
+    #[test]
+    fn invalid_dates() {
+        let error = |line: &str| line.parse::<Event>().unwrap_err().to_string();
+
+        assert!(error("[1518-13-01 00:00] wakes up").contains("a month from 01 to 12"));
+        assert!(error("[1518-02-29 00:00] wakes up").contains("a day of 1518-02, from 01 to 28"));
+        assert!(error("[1518-04-31 00:00] wakes up").contains("from 01 to 30"));
+        assert!(error("[1518-01-01 24:00] wakes up").contains("an hour from 00 to 23"));
+        assert!(error("[1518-01-01 00:60] wakes up").contains("a minute from 00 to 59"));
+        assert!("[1600-02-29 00:00] wakes up".parse::<Event>().is_ok());
+        assert!("[1700-02-29 00:00] wakes up".parse::<Event>().is_err());
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        let at =
+            |year, month, day, hour, minute| DateTime::new(year, month, day, hour, minute).unwrap();
+        let eve = at(1518, 12, 31, 23, 58);
+
+        assert_eq!(eve.add_minutes(3), Some(at(1519, 1, 1, 0, 1)));
+        assert_eq!(at(1519, 1, 1, 0, 1).minutes_since(&eve), 3);
+        assert_eq!(eve.minutes_since(&at(1519, 1, 1, 0, 1)), -3);
+        assert_eq!(
+            at(1520, 3, 1, 0, 0).add_minutes(-1),
+            Some(at(1520, 2, 29, 23, 59))
+        );
+        assert_eq!(
+            at(1518, 1, 1, 0, 0).add_minutes(366 * MINUTES_PER_DAY),
+            Some(at(1519, 1, 2, 0, 0))
+        );
+        assert_eq!(at(0, 1, 1, 0, 0).add_minutes(-1), None);
+
+        assert_eq!(eve.start_of_day(), at(1518, 12, 31, 0, 0));
+        assert_eq!(eve.start_of_next_day(), Some(at(1519, 1, 1, 0, 0)));
+        assert!(eve.same_day(&at(1518, 12, 31, 0, 0)));
+        assert!(!eve.same_day(&at(1519, 1, 1, 0, 0)));
+        assert_eq!(eve.minute_of_day(), 1438);
+        assert_eq!(eve.to_string(), "1518-12-31 23:58");
+    }
+
+    proptest! {
+        #[test]
+        fn minute_round_trip(minutes in 0..1_000_000_000i64, step in -10_000_000..10_000_000i64) {
+            let datetime = DateTime::new(0, 1, 1, 0, 0).unwrap().add_minutes(minutes).unwrap();
+            prop_assert_eq!(datetime.minutes(), minutes);
+
+            if let Some(later) = datetime.add_minutes(step) {
+                prop_assert_eq!(later.minutes_since(&datetime), step);
+                prop_assert_eq!(later > datetime, step > 0);
+            }
+        }
+    }
+
+    #[test]
+    fn shifts() {
+        let logs = Day04::parse(EXAMPLE).unwrap();
+        let at = |month, day, hour, minute| DateTime::new(1518, month, day, hour, minute).unwrap();
+
+        assert_eq!(logs.shifts().len(), 5);
+        let dates: Vec<_> = logs.shifts_for(99).map(|shift| shift.date).collect();
+        assert_eq!(
+            dates,
+            vec![at(11, 2, 0, 0), at(11, 4, 0, 0), at(11, 5, 0, 0)]
+        );
+        assert_eq!(
+            logs.shifts_for(10)
+                .map(Shift::minutes_asleep)
+                .collect::<Vec<_>>(),
+            vec![45, 5]
+        );
+
+        assert_eq!(logs.on_duty(at(10, 31, 23, 59)), None);
+        assert_eq!(logs.on_duty(at(11, 1, 0, 0)), Some(10));
+        assert_eq!(logs.on_duty(at(11, 1, 23, 58)), Some(99));
+        assert_eq!(logs.on_duty(at(11, 5, 0, 55)), Some(99));
+        assert_eq!(logs.on_duty(at(11, 5, 0, 56)), None);
+
+        assert!(logs.asleep(10, at(11, 1, 0, 5)));
+        assert!(!logs.asleep(10, at(11, 1, 0, 25)));
+        assert!(!logs.asleep(99, at(11, 1, 0, 5)));
+        assert!(logs.asleep(99, at(11, 5, 0, 54)));
+    }
+
+    #[test]
+    fn chart() {
+        let logs = Day04::parse(EXAMPLE).unwrap();
+        let header = "\
+Date   ID   Minute
+            000000000011111111112222222222333333333344444444445555555555
+            012345678901234567890123456789012345678901234567890123456789
+";
+
+        assert_eq!(
+            logs.chart(None, ..),
+            header.to_owned()
+                + "\
+11-01  #10  .....####################.....#########################.....
+11-02  #99  ........................................##########..........
+11-03  #10  ........................#####...............................
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+"
+        );
+
+        let from = DateTime::new(1518, 11, 3, 0, 0).unwrap();
+        assert_eq!(
+            logs.chart(Some(99), from..),
+            header.to_owned()
+                + "\
+11-04  #99  ....................................##########..............
+11-05  #99  .............................................##########.....
+"
+        );
+        assert_eq!(logs.chart(Some(10), from..from).lines().count(), 3);
+    }
+
+    #[test]
+    fn stats() {
+        let logs = Day04::parse(EXAMPLE).unwrap();
+
+        assert_eq!(logs.guards(), vec![10, 99]);
+        let stats = logs.stats(99).unwrap();
+        assert_eq!(
+            (
+                stats.minutes_asleep,
+                stats.midnight_minutes,
+                stats.shifts,
+                stats.naps
+            ),
+            (30, 30, 3, 3)
+        );
+        assert_eq!(stats.average_nap(), Some(10.0));
+        assert_eq!(logs.stats(10).unwrap().average_nap(), Some(50.0 / 3.0));
+        assert_eq!(logs.stats(1), None);
+        assert_eq!(logs.average_nap(), Some(80.0 / 6.0));
+
+        let ranks: Vec<_> = logs
+            .leaderboard()
+            .iter()
+            .map(|rank| (rank.rank, rank.stats.guard))
+            .collect();
+        assert_eq!(ranks, vec![(1, 10), (2, 99)]);
+
+        assert_eq!(
+            logs.top_minutes(99, 3).unwrap(),
+            vec![
+                SleepiestMinute {
+                    minute: 45,
+                    frequency: 3
+                },
+                SleepiestMinute {
+                    minute: 40,
+                    frequency: 2
+                },
+                SleepiestMinute {
+                    minute: 41,
+                    frequency: 2
+                },
+            ]
+        );
+        assert_eq!(logs.top_minutes(1, 3), None);
+        assert_eq!(logs.sleepiest_minutes_by_guard(10).unwrap().len(), 1);
+        assert_eq!(logs.sleepiest_minutes().len(), 1);
+    }
+
+    #[test]
+    fn ties() {
+        let logs = Day04::parse(
+            "[1518-11-01 00:00] Guard #7 begins shift
+[1518-11-01 00:10] falls asleep
+[1518-11-01 00:15] wakes up
+[1518-11-02 00:00] Guard #3 begins shift
+[1518-11-02 00:20] falls asleep
+[1518-11-02 00:25] wakes up
+[1518-11-03 00:00] Guard #5 begins shift",
+        )
+        .unwrap();
+
+        assert_eq!(logs.sleepiest_guards(), vec![3, 7]);
+        assert_eq!(logs.sleeps_most(), Some(3));
+        assert_eq!(logs.sleepiest_minutes_by_guard(3).unwrap().len(), 5);
+        assert_eq!(logs.sleepiest_minute_by_guard(3).unwrap().minute, 20);
+        assert_eq!(logs.sleepiest_minutes().len(), 10);
+        assert_eq!(logs.sleepiest_minute().unwrap().0, 3);
+        assert_eq!(logs.stats(5).unwrap().average_nap(), None);
+
+        // a guard who never slept has no sleepiest minutes rather than 60 tied ones
+        assert_eq!(logs.sleepiest_minutes_by_guard(5), Some(vec![]));
+        assert_eq!(logs.sleepiest_minute_by_guard(5), None);
+        assert_eq!(logs.top_minutes(5, 2), Some(vec![]));
+        assert_eq!(logs.top_minutes(3, 10).unwrap().len(), 5);
+
+        let awake = Day04::parse("[1518-11-01 00:00] Guard #7 begins shift").unwrap();
+        assert!(awake.sleepiest_guards().is_empty());
+        assert!(Day04::part1(&awake).is_err());
+        assert!(Day04::part2(&awake).is_err());
+
+        let ranks: Vec<_> = logs
+            .leaderboard()
+            .iter()
+            .map(|rank| (rank.rank, rank.stats.guard))
+            .collect();
+        assert_eq!(ranks, vec![(1, 3), (1, 7), (3, 5)]);
+    }
+
+    // guards can tie on either strategy, so the chosen guard and minute are checked to be one of
+    // the best rather than a particular one
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..100usize) {
+            let (input, logs) = common::generated::<Day04>(seed, size);
+            let tallies = reference::minutes_asleep(&input);
+            let total = |guard| tallies[&guard].iter().sum::<u32>();
+
+            // nobody has a sleepiest minute if nobody slept
+            if tallies.values().flatten().all(|&count| count == 0) {
+                prop_assert!(Day04::part1(&logs).is_err() && Day04::part2(&logs).is_err());
+                return Ok(());
+            }
+
+            let strategy = Day04::part1(&logs).unwrap();
+            let tally = tallies[&strategy.guard];
+            prop_assert_eq!(total(strategy.guard), tallies.keys().map(|&g| total(g)).max().unwrap());
+            prop_assert_eq!(tally[strategy.minute as usize], *tally.iter().max().unwrap());
+
+            let strategy = Day04::part2(&logs).unwrap();
+            let most = tallies.values().flat_map(|tally| tally.iter()).max().unwrap();
+            prop_assert_eq!(tallies[&strategy.guard][strategy.minute as usize], *most);
+        }
     }
 }
@@ -1,28 +1,26 @@
-use aoc04::{RawLogs, Result, ID};
+use aoc04::{Day04, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-fn main() -> Result<()> {
-    let mut input = String::new();
-    std::io::stdin().read_to_string(&mut input)?;
-
-    let raw_logs = RawLogs::new(&input)?;
-    let logs_by_guard = raw_logs.process_logs()?;
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
 
-    let sleepiest_guard: ID = logs_by_guard.sleeps_most();
-    let sm = logs_by_guard
-        .sleepiest_minute_by_guard(sleepiest_guard)
-        .unwrap();
+fn run() -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
 
-    println!(
-        "{}: {} - {}",
-        sleepiest_guard,
-        sm.minute,
-        sleepiest_guard * sm.minute
-    );
+    let logs_by_guard = Day04::parse(&input)?;
 
-    let sm = logs_by_guard.sleepiest_minute().unwrap();
+    let strategy = Day04::part1(&logs_by_guard)?;
+    println!("{}: {} - {}", strategy.guard, strategy.minute, strategy);
 
-    println!("{}: {} - {}", sm.0, sm.1.minute, sm.0 * sm.1.minute);
+    let strategy = Day04::part2(&logs_by_guard)?;
+    println!("{}: {} - {}", strategy.guard, strategy.minute, strategy);
 
     Ok(())
 }
@@ -0,0 +1,32 @@
+// the raw lines sorted as text and replayed, without parsing timestamps into records
+
+use std::collections::HashMap;
+
+// sorts the raw lines, which sort chronologically as their timestamps are zero padded, and
+// tallies how often each guard is asleep on each minute
+pub fn minutes_asleep(input: &str) -> HashMap<u32, [u32; 60]> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    lines.sort_unstable();
+
+    let mut tallies: HashMap<u32, [u32; 60]> = HashMap::new();
+    let mut guard = 0;
+    let mut asleep = 0;
+
+    for line in lines {
+        let minute: usize = line[15..17].parse().unwrap();
+        let event = &line[19..];
+
+        if let Some(rest) = event.strip_prefix("Guard #") {
+            guard = rest.split(' ').next().unwrap().parse().unwrap();
+            tallies.entry(guard).or_insert([0; 60]);
+        } else if event == "falls asleep" {
+            asleep = minute;
+        } else {
+            for count in &mut tallies.get_mut(&guard).unwrap()[asleep..minute] {
+                *count += 1;
+            }
+        }
+    }
+
+    tallies
+}
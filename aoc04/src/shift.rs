@@ -0,0 +1,76 @@
+use crate::{DateTime, ID, MINUTES_PER_DAY};
+
+// a guard asleep from start until, but not including, the minute they wake at end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Nap {
+    pub start: DateTime,
+    pub end: DateTime,
+}
+
+impl Nap {
+    /// The number of minutes slept
+    pub fn length(&self) -> u64 {
+        self.end.minutes_since(&self.start) as u64
+    }
+
+    pub fn asleep_at(&self, at: DateTime) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    /// The minutes of the midnight hour slept through, once for each night the nap covers
+    pub fn midnight_minutes(&self) -> impl Iterator<Item = u32> {
+        let (start, end) = (self.start.minutes(), self.end.minutes());
+        let first = start.div_euclid(MINUTES_PER_DAY);
+        let last = (end - 1).div_euclid(MINUTES_PER_DAY);
+
+        (first..=last).flat_map(move |day| {
+            let midnight = day * MINUTES_PER_DAY;
+            let from = start.max(midnight) - midnight;
+            let to = end.min(midnight + 60) - midnight;
+            (from..to.max(from)).map(|minute| minute as u32)
+        })
+    }
+}
+
+// a guard's time on duty, from beginning their shift until the next guard begins theirs. The
+// last shift in the logs ends the minute after its last event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shift {
+    pub guard: ID,
+    // midnight on the night of the shift, which is the following day for shifts that begin in
+    // the evening
+    pub date: DateTime,
+    pub start: DateTime,
+    pub end: DateTime,
+    pub naps: Vec<Nap>,
+}
+
+impl Shift {
+    pub(crate) fn new(guard: ID, start: DateTime) -> Self {
+        let date = if start.hour() >= 12 {
+            start.start_of_next_day().unwrap_or(start)
+        } else {
+            start.start_of_day()
+        };
+
+        Shift {
+            guard,
+            date,
+            start,
+            end: start,
+            naps: Vec::new(),
+        }
+    }
+
+    pub fn on_duty(&self, at: DateTime) -> bool {
+        self.start <= at && at < self.end
+    }
+
+    pub fn asleep_at(&self, at: DateTime) -> bool {
+        self.naps.iter().any(|nap| nap.asleep_at(at))
+    }
+
+    pub fn minutes_asleep(&self) -> u64 {
+        self.naps.iter().map(Nap::length).sum()
+    }
+}
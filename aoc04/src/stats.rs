@@ -0,0 +1,193 @@
+use crate::{LogsByGuard, Nap, Shift, ID};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SleepiestMinute {
+    pub minute: u32,
+    pub frequency: u32,
+}
+
+// how a guard spent their shifts
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardStats {
+    pub guard: ID,
+    // every minute asleep, including any outside the midnight hour
+    pub minutes_asleep: u64,
+    // only the minutes asleep during the midnight hour, which the strategies consider
+    pub midnight_minutes: u64,
+    pub shifts: usize,
+    pub naps: usize,
+}
+
+impl GuardStats {
+    /// The average length of the guard's naps in minutes, None if they never napped
+    pub fn average_nap(&self) -> Option<f64> {
+        if self.naps == 0 {
+            None
+        } else {
+            Some(self.minutes_asleep as f64 / self.naps as f64)
+        }
+    }
+}
+
+// a place on the leaderboard, guards who slept for as long share a rank and the next rank is
+// skipped for each guard sharing it, 1, 1, 3
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rank {
+    pub rank: usize,
+    pub stats: GuardStats,
+}
+
+impl LogsByGuard {
+    /// Every guard in the logs, in ascending order
+    pub fn guards(&self) -> Vec<ID> {
+        let mut guards: Vec<ID> = self.inner.keys().copied().collect();
+        guards.sort_unstable();
+        guards
+    }
+
+    pub fn stats(&self, id: ID) -> Option<GuardStats> {
+        let log = self.inner.get(&id)?;
+        let shifts: Vec<&Shift> = self.shifts_for(id).collect();
+
+        Some(GuardStats {
+            guard: id,
+            minutes_asleep: shifts.iter().map(|shift| shift.minutes_asleep()).sum(),
+            midnight_minutes: log.iter().map(|&f| u64::from(f)).sum(),
+            shifts: shifts.len(),
+            naps: shifts.iter().map(|shift| shift.naps.len()).sum(),
+        })
+    }
+
+    /// Every guard's stats, ranked by how many minutes of the midnight hour they slept, as the
+    /// strategies count them, then by ID
+    pub fn leaderboard(&self) -> Vec<Rank> {
+        let mut stats: Vec<GuardStats> = self
+            .guards()
+            .into_iter()
+            .filter_map(|id| self.stats(id))
+            .collect();
+        stats.sort_by_key(|stats| std::cmp::Reverse(stats.midnight_minutes));
+
+        let mut leaderboard: Vec<Rank> = Vec::with_capacity(stats.len());
+        for (i, stats) in stats.into_iter().enumerate() {
+            let rank = match leaderboard.last() {
+                Some(last) if last.stats.midnight_minutes == stats.midnight_minutes => last.rank,
+                _ => i + 1,
+            };
+            leaderboard.push(Rank { rank, stats });
+        }
+
+        leaderboard
+    }
+
+    /// The guards who spent the most minutes of the midnight hour asleep, in ascending order,
+    /// empty if nobody slept
+    pub fn sleepiest_guards(&self) -> Vec<ID> {
+        let slept = self
+            .guards()
+            .into_iter()
+            .map(|id| (id, self.inner[&id].iter().sum::<u32>()))
+            .filter(|&(_, minutes)| minutes > 0);
+
+        ties(slept, |&(_, minutes)| minutes)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// The guard who spent the most minutes of the midnight hour asleep, the lowest ID on a tie,
+    /// None if nobody slept
+    pub fn sleeps_most(&self) -> Option<ID> {
+        self.sleepiest_guards().first().copied()
+    }
+
+    /// Up to k of the minutes the guard was asleep on, most often first and earliest first on a
+    /// tie. None if there's no such guard, minutes they were never asleep on are left out.
+    pub fn top_minutes(&self, id: ID, k: usize) -> Option<Vec<SleepiestMinute>> {
+        let mut minutes = self.minutes(id)?;
+        minutes.sort_by_key(|sm| std::cmp::Reverse(sm.frequency));
+        minutes.truncate(k);
+
+        Some(minutes)
+    }
+
+    /// The minutes the guard was most often asleep on, in ascending order, empty if they never
+    /// slept
+    pub fn sleepiest_minutes_by_guard(&self, id: ID) -> Option<Vec<SleepiestMinute>> {
+        Some(ties(self.minutes(id)?, |sm| sm.frequency))
+    }
+
+    /// The guard's sleepiest minute, the earliest on a tie, None if they never slept
+    pub fn sleepiest_minute_by_guard(&self, id: ID) -> Option<SleepiestMinute> {
+        self.sleepiest_minutes_by_guard(id)?.first().copied()
+    }
+
+    /// Every guard and minute where the guard was asleep on that minute more often than any
+    /// guard was on any other, by guard and then minute
+    pub fn sleepiest_minutes(&self) -> Vec<(ID, SleepiestMinute)> {
+        let minutes = self.guards().into_iter().flat_map(|id| {
+            let minutes = self.minutes(id).unwrap_or_default();
+            minutes.into_iter().map(move |sm| (id, sm))
+        });
+
+        ties(minutes, |(_, sm)| sm.frequency)
+    }
+
+    /// The guard and minute most often slept on, the lowest ID and then earliest minute on a
+    /// tie, None if nobody slept
+    pub fn sleepiest_minute(&self) -> Option<(ID, SleepiestMinute)> {
+        self.sleepiest_minutes().first().copied()
+    }
+
+    /// The average nap length over every guard, None if nobody napped
+    pub fn average_nap(&self) -> Option<f64> {
+        let naps: Vec<&Nap> = self.shifts.iter().flat_map(|shift| &shift.naps).collect();
+        if naps.is_empty() {
+            return None;
+        }
+
+        Some(naps.iter().map(|nap| nap.length()).sum::<u64>() as f64 / naps.len() as f64)
+    }
+
+    // the minutes the guard was ever asleep on
+    fn minutes(&self, id: ID) -> Option<Vec<SleepiestMinute>> {
+        let log = self.inner.get(&id)?;
+
+        Some(
+            log.iter()
+                .enumerate()
+                .filter(|&(_, &frequency)| frequency > 0)
+                .map(|(minute, &frequency)| SleepiestMinute {
+                    minute: minute as u32,
+                    frequency,
+                })
+                .collect(),
+        )
+    }
+}
+
+// the items sharing the greatest key, in the order given
+fn ties<T, K, I, F>(items: I, key: F) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    let mut best: Vec<T> = Vec::new();
+    let mut most: Option<K> = None;
+
+    for item in items {
+        let k = key(&item);
+        match most.as_ref().map(|most| k.cmp(most)) {
+            Some(std::cmp::Ordering::Less) => continue,
+            Some(std::cmp::Ordering::Equal) => {}
+            _ => {
+                best.clear();
+                most = Some(k);
+            }
+        }
+        best.push(item);
+    }
+
+    best
+}
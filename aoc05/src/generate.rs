@@ -0,0 +1,44 @@
+use crate::Day05;
+use common::{Generator, Rng};
+
+impl Generator for Day05 {
+    const DEFAULT_SIZE: usize = 50_000;
+
+    // a polymer of size units. Units are either random or the opposite of the most recent unit
+    // that is yet to react, so that reactions nest and cascade as they do in the puzzle input.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut polymer = Vec::with_capacity(size + 1);
+        let mut unreacted = Vec::new();
+
+        for _ in 0..size {
+            match unreacted.last() {
+                Some(&last) if rng.chance(0.45) => {
+                    polymer.push(opposite(last));
+                    unreacted.pop();
+                }
+                _ => {
+                    let unit = b'a' + rng.below(26) as u8;
+                    let unit = if rng.chance(0.5) {
+                        unit.to_ascii_uppercase()
+                    } else {
+                        unit
+                    };
+
+                    polymer.push(unit);
+                    unreacted.push(unit);
+                }
+            }
+        }
+
+        polymer.push(b'\n');
+        String::from_utf8(polymer).unwrap()
+    }
+}
+
+fn opposite(unit: u8) -> u8 {
+    if unit.is_ascii_lowercase() {
+        unit.to_ascii_uppercase()
+    } else {
+        unit.to_ascii_lowercase()
+    }
+}
@@ -0,0 +1,92 @@
+use common::Solution;
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(polymer: &Self::Input) -> Result<Self::Part1> {
+        Ok(reduce_polymer(polymer, None))
+    }
+
+    fn part2(polymer: &Self::Input) -> Result<Self::Part2> {
+        // skip a through z as well as reducing to find the length of the shortest possible chain
+        let shortest = (b'a'..=b'z')
+            .map(|b| reduce_polymer(polymer, Some(b)))
+            .min()
+            .unwrap();
+
+        Ok(shortest)
+    }
+}
+
+type Stack<T> = Vec<T>;
+
+fn reacts(a: u8, b: u8) -> bool {
+    a != b && a.eq_ignore_ascii_case(&b)
+}
+
+// reduces the polymer by "reacting" all opposite pairs aA bB etc and by optionally skipping a pair
+pub fn reduce_polymer(p: &str, skip: Option<u8>) -> usize {
+    p.trim_end()
+        .bytes()
+        .fold(Stack::<u8>::new(), |mut stack, byte| match stack.last() {
+            _ if skip == Some(byte.to_ascii_lowercase()) => stack,
+            Some(&last) if reacts(last, byte) => {
+                stack.pop();
+                stack
+            }
+            _ => {
+                stack.push(byte);
+                stack
+            }
+        })
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let polymer = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part1(&polymer).unwrap(), 10);
+    }
+
+    #[test]
+    fn part2() {
+        let polymer = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part2(&polymer).unwrap(), 4);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 0..300usize) {
+            let (polymer, _) = common::generated::<Day05>(seed, size);
+
+            for skip in IntoIterator::into_iter([None, Some(b'a'), Some(b'q'), Some(b'z')]) {
+                prop_assert_eq!(
+                    reduce_polymer(&polymer, skip),
+                    reference::reduce_polymer(&polymer, skip)
+                );
+            }
+        }
+    }
+}
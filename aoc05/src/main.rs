@@ -1,44 +1,24 @@
+use aoc05::{Day05, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
 
-fn main() -> Result<()> {
+fn run() -> Result<()> {
     let mut input = String::new();
     io::stdin().read_to_string(&mut input)?;
 
-    println!("{}", reduce_polymer(&input, None));
+    let polymer = Day05::parse(&input)?;
 
-    // skip a through z as well as reducing to find the length of the shortest possible chain
-    let shortest = (b'a'..=b'z')
-        .map(|b| reduce_polymer(&input, Some(b)))
-        .min()
-        .unwrap();
+    println!("{}", Day05::part1(&polymer)?);
 
-    println!("{}", shortest);
+    println!("{}", Day05::part2(&polymer)?);
 
     Ok(())
 }
-
-type Stack<T> = Vec<T>;
-
-fn reacts(a: u8, b: u8) -> bool {
-    a != b && a.eq_ignore_ascii_case(&b)
-}
-
-// reduces the polymer by "reacting" all opposite pairs aA bB etc and by optionally skipping a pair
-fn reduce_polymer(p: &str, skip: Option<u8>) -> usize {
-    p.trim_end()
-        .bytes()
-        .fold(Stack::<u8>::new(), |mut stack, byte| match stack.last() {
-            _ if skip == Some(byte.to_ascii_lowercase()) => stack,
-            Some(&last) if reacts(last, byte) => {
-                stack.pop();
-                stack
-            }
-            _ => {
-                stack.push(byte);
-                stack
-            }
-        })
-        .len()
-}
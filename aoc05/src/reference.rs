@@ -0,0 +1,19 @@
+// one reaction at a time, rescanning the polymer from the start after each
+
+// removes the first pair of units that react and rescans the polymer from the start, until
+// no pair reacts
+pub fn reduce_polymer(polymer: &str, skip: Option<u8>) -> usize {
+    let mut units: Vec<u8> = polymer
+        .trim_end()
+        .bytes()
+        .filter(|unit| Some(unit.to_ascii_lowercase()) != skip)
+        .collect();
+
+    while let Some(i) = (1..units.len())
+        .find(|&i| units[i - 1] != units[i] && units[i - 1].eq_ignore_ascii_case(&units[i]))
+    {
+        units.drain(i - 1..=i);
+    }
+
+    units.len()
+}
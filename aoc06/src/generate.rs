@@ -0,0 +1,28 @@
+use crate::Day06;
+use common::{Generator, Point, Rng};
+use std::collections::HashSet;
+
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 50;
+
+    // size distinct locations scattered over a square that grows with size, the default size
+    // giving a square of 300 units as in the puzzle input
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let side = (size as i64 * 6).max(20);
+        let mut seen = HashSet::new();
+        let mut lines = String::new();
+
+        while seen.len() < size {
+            let location = Point::new(
+                rng.range(40, 40 + side) as i32,
+                rng.range(40, 40 + side) as i32,
+            );
+
+            if seen.insert(location) {
+                lines.push_str(&format!("{}, {}\n", location.x, location.y));
+            }
+        }
+
+        lines
+    }
+}
@@ -0,0 +1,160 @@
+use common::{BoundingBox, Grid, ParseError, Point, Solution};
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Point>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(common::parse_lines(input, parse_location)?)
+    }
+
+    fn part1(locations: &Self::Input) -> Result<Self::Part1> {
+        largest_finite_area(locations)
+    }
+
+    fn part2(locations: &Self::Input) -> Result<Self::Part2> {
+        safe_region_size(locations, 10_000)
+    }
+}
+
+pub fn largest_finite_area(locations: &[Point]) -> Result<i32> {
+    let bb = bounds(locations)?;
+    // each point is claimed by its closest location, or by none if several are equally close
+    let mut closest: Grid<Option<usize>> = bb.grid(None);
+
+    for p in bb.points() {
+        let dists: Vec<_> = locations.iter().map(|loc| loc.manhattan(&p)).collect();
+        let &min_dist = dists.iter().min().unwrap();
+        let v: Vec<_> = dists
+            .into_iter()
+            .enumerate()
+            .filter(|t| t.1 == min_dist)
+            .map(|t| t.0)
+            .collect();
+
+        // only count the point if it has one and only one closest location
+        if v.len() == 1 {
+            closest[p] = Some(v[0]);
+        }
+    }
+
+    let mut area_sizes = vec![0; locations.len()];
+    let mut infinite = vec![false; locations.len()];
+
+    for (pos, &owner) in closest.cells() {
+        if let Some(i) = owner {
+            // if the point is on the border then the area carries on outwards forever
+            if closest.on_edge(pos) {
+                infinite[i] = true;
+            }
+            area_sizes[i] += 1;
+        }
+    }
+
+    area_sizes
+        .into_iter()
+        .zip(infinite)
+        .filter(|&(_, infinite)| !infinite)
+        .map(|(size, _)| size)
+        .max()
+        .ok_or_else(|| From::from("every area is infinite"))
+}
+
+// every point more than limit / n outside the bounding box is over the limit from n locations,
+// as it is further than that from each of them, so the region is within that margin of the box
+pub fn safe_region_size(locations: &[Point], limit: i32) -> Result<usize> {
+    let mut bb = bounds(locations)?;
+    let margin = (limit - 1).max(0) / locations.len() as i32;
+    bb.expand(bb.min() - Point::new(margin, margin));
+    bb.expand(bb.max() + Point::new(margin, margin));
+
+    let area = bb
+        .points()
+        .map(|p| locations.iter().map(|loc| loc.manhattan(&p)).sum::<i32>())
+        .filter(|&d| d < limit)
+        .count();
+
+    Ok(area)
+}
+
+// aaaaa.cccc
+// aAaaa.cccc
+// aaaddecccc
+// aadddeccCc
+// ..dDdeeccc
+// bb.deEeecc
+// bBb.eeee..
+// bbb.eeefff
+// bbb.eeffff
+// bbb.ffffFf
+//
+// hypothesis: if a location (A, B, C) is on the bounding box, or is the nearest coordinate to a point on
+// the bounding box then its area will extend infinitely
+
+fn bounds(locations: &[Point]) -> Result<BoundingBox> {
+    BoundingBox::from_points(locations.iter().copied()).ok_or_else(|| From::from("No locations"))
+}
+
+// a location of the form 1, 6
+fn parse_location(s: &str) -> std::result::Result<Point, ParseError> {
+    if let Some(i) = s.find(',') {
+        let x: i32 = common::number(s, common::trim_span(s, 0..i), "an x coordinate")?;
+        let y: i32 = common::number(s, common::trim_span(s, i + 1..s.len()), "a y coordinate")?;
+
+        return Ok(Point { x, y });
+    }
+
+    Err(ParseError::unrecognized(
+        s,
+        "a coordinate of the form <x>, <y>",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let locations = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part1(&locations).unwrap(), 17);
+    }
+
+    #[test]
+    fn part2() {
+        // the example uses a smaller limit than the puzzle
+        let locations = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(safe_region_size(&locations, 32).unwrap(), 16);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..12usize, limit in 0..300i32) {
+            let (_, locations) = common::generated::<Day06>(seed, size);
+
+            prop_assert_eq!(
+                largest_finite_area(&locations).ok(),
+                reference::largest_finite_area(&locations)
+            );
+            prop_assert_eq!(
+                safe_region_size(&locations, limit).unwrap(),
+                reference::safe_region_size(&locations, limit)
+            );
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// brute force over a padded box, finding each point's closest location from scratch
+
+use common::Point;
+use std::collections::{HashMap, HashSet};
+
+// the location strictly closest to p, if there is one
+fn owner(locations: &[Point], p: Point) -> Option<usize> {
+    let distance = |i: usize| locations[i].manhattan(&p);
+    let closest = (0..locations.len()).min_by_key(|&i| distance(i))?;
+    let ties = (0..locations.len())
+        .filter(|&i| distance(i) == distance(closest))
+        .count();
+
+    if ties == 1 {
+        Some(closest)
+    } else {
+        None
+    }
+}
+
+// claims a ring of points one beyond the furthest locations, anything owning part of the ring
+// owns everything further out in that direction too and so has an infinite area
+pub fn largest_finite_area(locations: &[Point]) -> Option<i32> {
+    let min_x = locations.iter().map(|p| p.x).min()? - 1;
+    let max_x = locations.iter().map(|p| p.x).max()? + 1;
+    let min_y = locations.iter().map(|p| p.y).min()? - 1;
+    let max_y = locations.iter().map(|p| p.y).max()? + 1;
+
+    let mut areas: HashMap<usize, i32> = HashMap::new();
+    let mut infinite = HashSet::new();
+
+    for x in min_x..=max_x {
+        for y in min_y..=max_y {
+            if let Some(i) = owner(locations, Point::new(x, y)) {
+                if x == min_x || x == max_x || y == min_y || y == max_y {
+                    infinite.insert(i);
+                } else {
+                    *areas.entry(i).or_default() += 1;
+                }
+            }
+        }
+    }
+
+    areas
+        .into_iter()
+        .filter(|(i, _)| !infinite.contains(i))
+        .map(|(_, area)| area)
+        .max()
+}
+
+// searches every point close enough to the first location to possibly be in the region
+pub fn safe_region_size(locations: &[Point], limit: i32) -> usize {
+    let first = locations[0];
+
+    (first.x - limit..=first.x + limit)
+        .flat_map(|x| (first.y - limit..=first.y + limit).map(move |y| Point::new(x, y)))
+        .filter(|p| locations.iter().map(|loc| loc.manhattan(p)).sum::<i32>() < limit)
+        .count()
+}
@@ -0,0 +1,39 @@
+use crate::Day07;
+use common::{Generator, Rng};
+
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 26;
+
+    // a random acyclic graph over size steps, which is clamped to 2..=26 as steps are single
+    // letters. The steps are put in a random order and every step after the first depends on
+    // at least one step before it, with further dependencies between steps added at random.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut steps: Vec<u8> = (b'A'..=b'Z').collect();
+        rng.shuffle(&mut steps);
+        steps.truncate(size.clamp(2, 26));
+
+        let mut edges = Vec::new();
+
+        for j in 1..steps.len() {
+            let required = rng.index(j);
+
+            for i in 0..j {
+                if i == required || rng.chance(0.15) {
+                    edges.push((steps[i], steps[j]));
+                }
+            }
+        }
+
+        rng.shuffle(&mut edges);
+
+        edges
+            .into_iter()
+            .map(|(before, after)| {
+                format!(
+                    "Step {} must be finished before step {} can begin.\n",
+                    before as char, after as char
+                )
+            })
+            .collect()
+    }
+}
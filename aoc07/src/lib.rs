@@ -1,10 +1,43 @@
+use common::{ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Graph;
+    type Part1 = String;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let adjacencies: Vec<Adjacent> = common::parse_lines(input, str::parse)?;
+
+        Ok(Graph::new(&adjacencies))
+    }
+
+    fn part1(graph: &Self::Input) -> Result<Self::Part1> {
+        let mut order: Vec<u8> = vec![];
+        graph.step_order(&mut order);
+
+        Ok(String::from_utf8(order)?)
+    }
+
+    fn part2(graph: &Self::Input) -> Result<Self::Part2> {
+        let mut order: Vec<u8> = vec![];
+
+        graph.with_workers(&mut order, 5, 60)
+    }
+}
+
 // EXAMPLE INPUT:
 // Step C must be finished before step A can begin.
 // Step C must be finished before step F can begin.
@@ -51,11 +84,9 @@ impl Graph {
         let mut all_dependent: HashSet<u8> = HashSet::new();
 
         for adjacent in input {
-            let list = adjacencies.entry(adjacent.0).or_insert_with(|| vec![]);
+            let list = adjacencies.entry(adjacent.0).or_default();
             list.push(adjacent.1);
-            let set = dependencies
-                .entry(adjacent.1)
-                .or_insert_with(|| HashSet::new());
+            let set = dependencies.entry(adjacent.1).or_default();
             set.insert(adjacent.0);
             all_dependent.insert(adjacent.1);
         }
@@ -75,7 +106,7 @@ impl Graph {
 
     pub fn step_order(&self, order: &mut Vec<u8>) {
         let mut visited: HashSet<u8> = HashSet::new();
-        let mut queue: Vec<u8> = self.start.iter().copied().collect();
+        let mut queue: Vec<u8> = self.start.to_vec();
         queue.sort();
         queue.reverse();
 
@@ -99,12 +130,19 @@ impl Graph {
     }
 
     // fills order up with the order in which the jobs are completed and returns the total time
-    // taken to complete the jobs.
-    pub fn with_workers(&self, order: &mut Vec<u8>) -> u32 {
+    // taken to complete the jobs. Each step takes base seconds plus its position in the alphabet.
+    // Without any workers nothing would ever be completed, so there must be at least one.
+    pub fn with_workers(&self, order: &mut Vec<u8>, count: usize, base: u32) -> Result<u32> {
+        if count == 0 {
+            return Err(From::from(
+                "at least one worker is needed to complete the steps",
+            ));
+        }
+
         let mut completed: HashSet<u8> = HashSet::new();
-        let mut workers = Workers::new(5);
+        let mut workers = Workers::new(count, base);
         let mut time_elapsed = 0;
-        let mut queue: Vec<u8> = self.start.iter().copied().collect();
+        let mut queue: Vec<u8> = self.start.to_vec();
         queue.sort();
         queue.reverse();
 
@@ -141,11 +179,11 @@ impl Graph {
             time_elapsed += 1;
         }
 
-        time_elapsed
+        Ok(time_elapsed)
     }
 
     fn visitable(&self, to_visit: &u8, visited: &HashSet<u8>) -> bool {
-        if visited.contains(&to_visit) {
+        if visited.contains(to_visit) {
             return false;
         }
         // If a node in the the dependencies of to_visit has not yet been visited then we cannot
@@ -160,9 +198,9 @@ impl Graph {
 pub struct Adjacent(u8, u8);
 
 impl FromStr for Adjacent {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
         lazy_static! {
             static ref RE: Regex =
                 Regex::new(r"Step ([A-Z]) must be finished before step ([A-Z]) can begin.")
@@ -170,7 +208,12 @@ impl FromStr for Adjacent {
         }
 
         let caps = match RE.captures(s) {
-            None => return Err(From::from("unrecognized statement")),
+            None => {
+                return Err(ParseError::unrecognized(
+                    s,
+                    "Step <A-Z> must be finished before step <A-Z> can begin.",
+                ))
+            }
             Some(caps) => caps,
         };
 
@@ -181,15 +224,16 @@ impl FromStr for Adjacent {
 
 pub struct Workers {
     workers: Vec<Status>,
+    base: u32,
 }
 
 type ID = usize;
 
 impl Workers {
-    pub fn new(count: usize) -> Self {
+    pub fn new(count: usize, base: u32) -> Self {
         let workers = vec![Status::Idle; count];
 
-        Workers { workers }
+        Workers { workers, base }
     }
 
     pub fn available(&self) -> Vec<ID> {
@@ -212,7 +256,7 @@ impl Workers {
 
         *worker = Status::Working {
             step,
-            remaining: step - b'A' + 1 + 60,
+            remaining: u32::from(step - b'A') + 1 + self.base,
         };
     }
 
@@ -253,12 +297,13 @@ impl Workers {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Status {
     Idle,
-    Working { step: u8, remaining: u8 },
+    Working { step: u8, remaining: u32 },
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn part1() {
@@ -281,4 +326,41 @@ mod tests {
         graph.step_order(&mut order);
         assert_eq!(std::str::from_utf8(&order).unwrap(), "CABDFE");
     }
+
+    #[test]
+    fn part2() {
+        // the example uses fewer workers and shorter steps than the puzzle
+        let graph = Day07::parse(include_str!("../input/example.txt")).unwrap();
+        let mut order: Vec<u8> = vec![];
+        let duration = graph.with_workers(&mut order, 2, 0).unwrap();
+        assert_eq!(std::str::from_utf8(&order).unwrap(), "CABFDE");
+        assert_eq!(duration, 15);
+
+        assert!(graph.with_workers(&mut vec![], 0, 0).is_err());
+        assert_eq!(
+            graph.with_workers(&mut vec![], 1, 250).unwrap(),
+            6 * 250 + 21
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn matches_reference(
+            seed in any::<u64>(),
+            size in 2..=26usize,
+            count in 1..=5usize,
+            base in 0..=60u32,
+        ) {
+            let (input, graph) = common::generated::<Day07>(seed, size);
+            let requirements = reference::requirements(&input);
+
+            prop_assert_eq!(Day07::part1(&graph).unwrap(), reference::step_order(&requirements));
+            prop_assert_eq!(
+                graph.with_workers(&mut vec![], count, base).unwrap(),
+                reference::duration(&requirements, count, base)
+            );
+        }
+    }
 }
@@ -1,22 +1,24 @@
-use aoc07::{Adjacent, Graph, Result};
+use aoc07::{Day07, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-fn main() -> Result<()> {
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
     let mut input = String::new();
     io::stdin().read_to_string(&mut input)?;
-    let mut adjacencies: Vec<Adjacent> = Vec::new();
 
-    for line in input.lines() {
-        adjacencies.push(line.parse()?);
-    }
+    let graph = Day07::parse(&input)?;
+
+    println!("{}", Day07::part1(&graph)?);
 
-    let graph = Graph::new(&adjacencies);
-    let mut order: Vec<u8> = vec![];
-    graph.step_order(&mut order);
-    println!("{}", std::str::from_utf8(&order)?);
-    order.clear();
-    let duration = graph.with_workers(&mut order);
-    println!("{} {}", std::str::from_utf8(&order)?, duration);
+    println!("{}", Day07::part2(&graph)?);
 
     Ok(())
 }
@@ -0,0 +1,73 @@
+// steps and workers scheduled by rescanning the raw requirement pairs every second
+
+use std::collections::{BTreeSet, HashSet};
+
+// the requirements as (before, after) pairs, read straight from the input
+pub fn requirements(input: &str) -> Vec<(u8, u8)> {
+    input
+        .lines()
+        .map(|line| {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            (words[1].as_bytes()[0], words[7].as_bytes()[0])
+        })
+        .collect()
+}
+
+// the steps not yet done or started whose requirements are all done, alphabetically
+fn ready(requirements: &[(u8, u8)], done: &HashSet<u8>, started: &HashSet<u8>) -> Vec<u8> {
+    let steps: BTreeSet<u8> = requirements.iter().flat_map(|&(a, b)| vec![a, b]).collect();
+
+    steps
+        .into_iter()
+        .filter(|step| !done.contains(step) && !started.contains(step))
+        .filter(|step| {
+            requirements
+                .iter()
+                .filter(|&&(_, after)| after == *step)
+                .all(|(before, _)| done.contains(before))
+        })
+        .collect()
+}
+
+// rescans every step for the first one that's ready each time a step is done
+pub fn step_order(requirements: &[(u8, u8)]) -> String {
+    let mut done = HashSet::new();
+    let mut order = String::new();
+
+    while let Some(&step) = ready(requirements, &done, &HashSet::new()).first() {
+        done.insert(step);
+        order.push(step as char);
+    }
+
+    order
+}
+
+// simulates the workers a second at a time
+pub fn duration(requirements: &[(u8, u8)], count: usize, base: u32) -> u32 {
+    let mut done = HashSet::new();
+    let mut started = HashSet::new();
+    let mut working: Vec<(u8, u32)> = Vec::new();
+    let mut seconds = 0;
+
+    loop {
+        for step in ready(requirements, &done, &started) {
+            if working.len() < count {
+                started.insert(step);
+                working.push((step, u32::from(step - b'A') + 1 + base));
+            }
+        }
+
+        if working.is_empty() {
+            return seconds;
+        }
+
+        seconds += 1;
+        for (step, remaining) in working.iter_mut() {
+            *remaining -= 1;
+            if *remaining == 0 {
+                done.insert(*step);
+            }
+        }
+        working.retain(|&(_, remaining)| remaining > 0);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::Day08;
+use common::{Generator, Rng};
+
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    // the flattened form of a random tree of size nodes, on a single line
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut flat = Vec::new();
+        tree(rng, size.max(1), &mut flat);
+
+        let line: Vec<String> = flat.iter().map(u32::to_string).collect();
+
+        line.join(" ") + "\n"
+    }
+}
+
+// appends a node heading a subtree of size nodes. The nodes below it are shared out between
+// up to 5 children, each child getting at least one.
+fn tree(rng: &mut Rng, size: usize, flat: &mut Vec<u32>) {
+    let below = size - 1;
+    let child_count = if below == 0 {
+        0
+    } else {
+        rng.range(1, below.min(5) as i64) as usize
+    };
+    let meta_count = rng.range(1, 11) as u32;
+
+    let mut sizes = vec![1; child_count];
+    for _ in child_count..below {
+        sizes[rng.index(child_count)] += 1;
+    }
+
+    flat.push(child_count as u32);
+    flat.push(meta_count);
+
+    for size in sizes {
+        tree(rng, size, flat);
+    }
+
+    // metadata entries that refer to children, along with some that refer to nothing
+    for _ in 0..meta_count {
+        flat.push(rng.range(1, child_count as i64 + 3) as u32);
+    }
+}
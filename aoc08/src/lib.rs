@@ -1,42 +1,131 @@
+use common::{ErrorKind, ParseError, Solution};
+use std::ops::Range;
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
-#[derive(Default)]
-pub struct Node {
-    metadata: Vec<i32>,
-    children: Vec<Node>,
-    // length of metadata + length of children + 2 for header
-    len: usize,
-}
+pub struct Day08;
 
-impl Node {
-    pub fn new(input: &[i32]) -> Result<Self> {
-        if input.len() < 2 {
-            return Err(From::from("invalid header"));
+impl Solution for Day08 {
+    type Input = Node;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut tokens = vec![];
+
+        for (i, line) in input.lines().enumerate() {
+            for (columns, _) in common::words(line) {
+                let value = common::number(line, columns.clone(), "an integer")
+                    .map_err(|e| e.at_line(i + 1))?;
+                tokens.push(Token {
+                    value,
+                    line: i + 1,
+                    source: line,
+                    columns,
+                });
+            }
         }
 
-        let (child_count, meta_count) = (input[0], input[1]);
-        let mut node = Node {
-            len: 2,
-            ..Node::default()
+        let mut parser = Parser {
+            tokens,
+            next: 0,
+            last_line: input
+                .lines()
+                .enumerate()
+                .last()
+                .map_or((1, ""), |(i, line)| (i + 1, line)),
         };
-        for _ in 0..child_count {
-            let child = Node::new(&input[node.len..])?;
-            node.len += child.len;
-            node.children.push(child);
+        let root = parser.node()?;
+
+        match parser.tokens.get(parser.next) {
+            Some(token) => Err(From::from(token.error(
+                ErrorKind::Unrecognized,
+                "the end of the input after the root node",
+            ))),
+            None => Ok(root),
         }
-        for _ in 0..meta_count {
-            let meta = match input.get(node.len) {
-                None => return Err(From::from("No metadata matching header")),
-                Some(&i) if i < 1 => return Err(From::from("invalid meta data")),
-                Some(&i) => i,
-            };
-            node.metadata.push(meta);
-            node.len += 1;
+    }
+
+    fn part1(node: &Self::Input) -> Result<Self::Part1> {
+        Ok(node.sum_metadata())
+    }
+
+    fn part2(node: &Self::Input) -> Result<Self::Part2> {
+        Ok(node.sum_metadata_complex())
+    }
+}
+
+// a number in the input and where it was found, so that errors can point at it
+struct Token<'a> {
+    value: i32,
+    line: usize,
+    source: &'a str,
+    columns: Range<usize>,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: ErrorKind, expected: &str) -> ParseError {
+        ParseError::new(kind, self.source, self.columns.clone(), expected).at_line(self.line)
+    }
+}
+
+// reads nodes from the numbers in the order they appear
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    next: usize,
+    // the number and text of the last line, where the input ends
+    last_line: (usize, &'a str),
+}
+
+impl Parser<'_> {
+    // the next number, which must be at least min
+    fn take(&mut self, min: i32, expected: &str) -> std::result::Result<i32, ParseError> {
+        let token = match self.tokens.get(self.next) {
+            Some(token) => token,
+            None => {
+                let (line, source) = self.last_line;
+                return Err(ParseError::unexpected_end(source, expected).at_line(line));
+            }
+        };
+
+        if token.value < min {
+            return Err(token.error(
+                ErrorKind::InvalidValue,
+                &format!("{} of at least {}", expected, min),
+            ));
         }
 
-        Ok(node)
+        self.next += 1;
+        Ok(token.value)
     }
 
+    fn node(&mut self) -> std::result::Result<Node, ParseError> {
+        let child_count = self.take(0, "a child count")?;
+        let meta_count = self.take(0, "a metadata count")?;
+
+        let children = (0..child_count)
+            .map(|_| self.node())
+            .collect::<std::result::Result<_, _>>()?;
+        let metadata = (0..meta_count)
+            .map(|_| self.take(1, "a metadata entry"))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Node { metadata, children })
+    }
+}
+
+#[derive(Debug)]
+pub struct Node {
+    metadata: Vec<i32>,
+    children: Vec<Node>,
+}
+
+impl Node {
     pub fn sum_metadata(&self) -> i32 {
         let mut sum = self.metadata.iter().sum();
 
@@ -47,7 +136,7 @@ impl Node {
     }
 
     pub fn sum_metadata_complex(&self) -> i32 {
-        if self.children.len() == 0 {
+        if self.children.is_empty() {
             return self.metadata.iter().sum();
         }
 
@@ -60,3 +149,58 @@ impl Node {
         sum
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let node = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part1(&node).unwrap(), 138);
+    }
+
+    #[test]
+    fn part2() {
+        let node = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part2(&node).unwrap(), 66);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = |input: &str| {
+            let error = Day08::parse(input).unwrap_err();
+            let error = error.downcast_ref::<ParseError>().unwrap();
+            (error.kind.clone(), error.line, error.text().to_string())
+        };
+
+        assert_eq!(error("2 3"), (ErrorKind::UnexpectedEnd, 1, "".to_string()));
+        assert_eq!(
+            error("0 0\n1 2 3"),
+            (ErrorKind::Unrecognized, 2, "1".to_string())
+        );
+        assert_eq!(
+            error("1 1\n0 1 0\n2"),
+            (ErrorKind::InvalidValue, 2, "0".to_string())
+        );
+        assert_eq!(
+            error("0 -1"),
+            (ErrorKind::InvalidValue, 1, "-1".to_string())
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..300usize) {
+            let (input, node) = common::generated::<Day08>(seed, size);
+            let flat: Vec<i32> = input.split_whitespace().map(|n| n.parse().unwrap()).collect();
+            let (sum, value) = reference::sums(&flat);
+
+            prop_assert_eq!(Day08::part1(&node).unwrap(), sum);
+            prop_assert_eq!(Day08::part2(&node).unwrap(), value);
+        }
+    }
+}
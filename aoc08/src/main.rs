@@ -1,19 +1,24 @@
-use aoc08::{Node, Result};
+use aoc08::{Day08, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-fn main() -> Result<()> {
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
     let mut input = String::new();
     io::stdin().read_to_string(&mut input)?;
-    let mut flat: Vec<i32> = vec![];
 
-    for n in input.split_ascii_whitespace() {
-        flat.push(n.parse()?);
-    }
+    let node = Day08::parse(&input)?;
 
-    let node = Node::new(&flat)?;
+    println!("{}", Day08::part1(&node)?);
 
-    println!("{}", node.sum_metadata());
-    println!("{}", node.sum_metadata_complex());
+    println!("{}", Day08::part2(&node)?);
 
     Ok(())
 }
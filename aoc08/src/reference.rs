@@ -0,0 +1,48 @@
+// the flat numbers walked with an explicit stack instead of building a tree
+
+struct Frame {
+    children: usize,
+    metadata: usize,
+    values: Vec<i32>,
+}
+
+// walks the flattened tree with an explicit stack of the nodes whose children are still being
+// read, returning the sum of all the metadata and the value of the root
+pub fn sums(flat: &[i32]) -> (i32, i32) {
+    let header = |i: usize| Frame {
+        children: flat[i] as usize,
+        metadata: flat[i + 1] as usize,
+        values: Vec::new(),
+    };
+    let mut stack = vec![header(0)];
+    let mut i = 2;
+    let mut total = 0;
+
+    loop {
+        let top = stack.last().unwrap();
+        if top.values.len() < top.children {
+            stack.push(header(i));
+            i += 2;
+            continue;
+        }
+
+        let node = stack.pop().unwrap();
+        let metadata = &flat[i..i + node.metadata];
+        i += node.metadata;
+        total += metadata.iter().sum::<i32>();
+
+        let value = if node.children == 0 {
+            metadata.iter().sum()
+        } else {
+            metadata
+                .iter()
+                .filter_map(|&m| node.values.get(m as usize - 1))
+                .sum()
+        };
+
+        match stack.last_mut() {
+            Some(parent) => parent.values.push(value),
+            None => return (total, value),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::Day09;
+use common::{Generator, Rng};
+
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 70_000;
+
+    // a game whose last marble is worth size points, played by between 2 and 500 players
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        format!(
+            "{} players; last marble is worth {} points\n",
+            rng.range(2, 500),
+            size.max(1)
+        )
+    }
+}
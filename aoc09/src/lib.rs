@@ -1,16 +1,96 @@
+use common::{ErrorKind, ParseError, Solution};
 use std::collections::VecDeque;
+use std::str::FromStr;
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Rules;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = input.lines().next().unwrap_or("");
+
+        Ok(line.parse().map_err(|e: ParseError| e.at_line(1))?)
+    }
+
+    fn part1(rules: &Self::Input) -> Result<Self::Part1> {
+        let mut game = Game::new(rules.player_count, rules.last);
+
+        Ok(game.play())
+    }
+
+    fn part2(rules: &Self::Input) -> Result<Self::Part2> {
+        let last = rules
+            .last
+            .checked_mul(100)
+            .ok_or("the last marble is too valuable to play a game 100 times larger")?;
+        let mut game = Game::new(rules.player_count, last);
+
+        Ok(game.play())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    pub player_count: usize,
+    pub last: u32,
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
+        // input of form
+        // 491 players; last marble is worth 71058 points
+        let words: Vec<_> = common::words(s).collect();
+        let shape: Vec<&str> = words.iter().map(|w| w.1).collect();
+
+        match shape[..] {
+            [_, "players;", "last", "marble", "is", "worth", _, "points"] => {
+                let player_count = common::number(s, words[0].0.clone(), "a number of players")?;
+
+                if player_count == 0 {
+                    return Err(ParseError::new(
+                        ErrorKind::InvalidValue,
+                        s,
+                        words[0].0.clone(),
+                        "at least one player",
+                    ));
+                }
+
+                Ok(Rules {
+                    player_count,
+                    last: common::number(s, words[6].0.clone(), "a marble value")?,
+                })
+            }
+            _ => Err(ParseError::unrecognized(
+                s,
+                "<players> players; last marble is worth <points> points",
+            )),
+        }
+    }
+}
 
 pub struct Game {
     last: u32,
     marbles: VecDeque<u32>,
-    scores: Vec<u32>,
+    scores: Vec<u64>,
 }
 
 impl Game {
     pub fn new(player_count: usize, last: u32) -> Self {
         let mut marbles: VecDeque<u32> = VecDeque::with_capacity(last as usize);
         marbles.push_back(0);
-        let scores: Vec<u32> = vec![0; player_count];
+        let scores: Vec<u64> = vec![0; player_count];
         Game {
             last,
             marbles,
@@ -18,8 +98,8 @@ impl Game {
         }
     }
 
-    /// plays the game returning the highest score
-    pub fn play(&mut self) -> u32 {
+    /// plays the game returning the highest score, 0 if there are no players
+    pub fn play(&mut self) -> u64 {
         // we model the circle as a double ended queue keeping the current marble at the back of
         // the queue with the marble that is 1 step clockwise at the front of the queue
         for (v, p) in (1..=self.last).zip((0..self.scores.len()).cycle()) {
@@ -30,7 +110,7 @@ impl Game {
                 }
 
                 let t = self.marbles.pop_back().unwrap();
-                self.scores[p] += v + t;
+                self.scores[p] += u64::from(v) + u64::from(t);
                 let t = self.marbles.pop_front().unwrap();
                 self.marbles.push_back(t);
             } else {
@@ -40,6 +120,49 @@ impl Game {
             }
         }
 
-        *self.scores.iter().max().unwrap()
+        self.scores.iter().copied().max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1() {
+        let rules = Day09::parse(include_str!("../input/example.txt")).unwrap();
+        assert_eq!(Day09::part1(&rules).unwrap(), 32);
+
+        for &(player_count, last, high_score) in &[
+            (10, 1618, 8317),
+            (13, 7999, 146373),
+            (17, 1104, 2764),
+            (21, 6111, 54718),
+            (30, 5807, 37305),
+        ] {
+            assert_eq!(Game::new(player_count, last).play(), high_score);
+        }
+    }
+
+    #[test]
+    fn invalid_rules() {
+        assert!(Day09::parse("0 players; last marble is worth 100 points").is_err());
+        assert!(Day09::part2(&Rules {
+            player_count: 10,
+            last: 50_000_000
+        })
+        .is_err());
+        assert_eq!(Game::new(0, 100).play(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..3000usize) {
+            let (_, rules) = common::generated::<Day09>(seed, size);
+            let mut game = Game::new(rules.player_count, rules.last);
+
+            prop_assert_eq!(game.play(), reference::high_score(rules.player_count, rules.last));
+        }
     }
 }
@@ -1,14 +1,24 @@
-use aoc09::Game;
-
-const PLAYER_COUNT: usize = 491;
-const FINAL_MARBLE: u32 = 71058;
+use aoc09::{Day09, Result};
+use common::Solution;
+use std::io::{self, Read};
+use std::process;
 
 fn main() {
-    let mut game = Game::new(PLAYER_COUNT, FINAL_MARBLE);
-    let high_score = game.play();
-    println!("{}", high_score);
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let rules = Day09::parse(&input)?;
+
+    println!("{}", Day09::part1(&rules)?);
+
+    println!("{}", Day09::part2(&rules)?);
 
-    let mut game = Game::new(PLAYER_COUNT, FINAL_MARBLE * 100);
-    let high_score = game.play();
-    println!("{}", high_score);
+    Ok(())
 }
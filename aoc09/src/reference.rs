@@ -0,0 +1,22 @@
+// the game played on a literal Vec circle, inserting and removing by index
+
+// plays the game on a literal circle of marbles, inserting and removing marbles by index
+pub fn high_score(player_count: usize, last: u32) -> u64 {
+    let mut circle = vec![0];
+    let mut current = 0;
+    let mut scores = vec![0; player_count];
+
+    for marble in 1..=last {
+        let player = (marble as usize - 1) % player_count;
+
+        if marble % 23 == 0 {
+            current = (current + circle.len() - 7) % circle.len();
+            scores[player] += u64::from(marble + circle.remove(current));
+        } else {
+            current = (current + 1) % circle.len() + 1;
+            circle.insert(current, marble);
+        }
+    }
+
+    scores.into_iter().max().unwrap()
+}
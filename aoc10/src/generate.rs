@@ -0,0 +1,88 @@
+use crate::{Day10, Result};
+use common::{Generator, Point, Rng};
+
+// a small block font, each letter is 6 rows high and drawn on a 4 column grid
+pub(crate) const FONT: [(char, [&str; 6]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###.", ".#..", ".#..", ".#..", ".#..", "###."]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 8;
+
+    // a message of size random letters that appears after up to 20000 seconds
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let text: String = (0..size.max(1)).map(|_| rng.choose(&FONT).0).collect();
+        let seconds = rng.range(1, 20_000) as u32;
+
+        message(rng, &text, seconds).unwrap()
+    }
+}
+
+/// Generates points of light that spell out text after the given number of seconds. Letters
+/// are drawn with a small block font covering the letters ABCEFGHIJKLOPRSUZ.
+pub fn message(rng: &mut Rng, text: &str, seconds: u32) -> Result<String> {
+    let offset = Point::new(rng.range(-200, 200) as i32, rng.range(-200, 200) as i32);
+    let mut pixels = Vec::new();
+
+    for (i, letter) in text.chars().enumerate() {
+        let glyph = FONT
+            .iter()
+            .find(|(c, _)| *c == letter)
+            .ok_or_else(|| format!("no glyph for the letter {:?}", letter))?
+            .1;
+
+        for (y, row) in glyph.iter().enumerate() {
+            for (x, _) in row.bytes().enumerate().filter(|&(_, b)| b == b'#') {
+                pixels.push(offset + Point::new(i as i32 * 5 + x as i32, y as i32));
+            }
+        }
+    }
+
+    let mut lights: Vec<(Point, Point)> = pixels
+        .iter()
+        .map(|&pixel| {
+            let velocity = Point::new(rng.range(-5, 5) as i32, rng.range(-5, 5) as i32);
+            (pixel, velocity)
+        })
+        .collect();
+
+    // a pair of lights moving up and down through both the top and bottom rows of the message
+    // make sure that it is taller one second either side of when it appears, so the message is
+    // where the points converge
+    for &row in &[0, 5] {
+        if let Some(&pixel) = pixels.iter().find(|p| p.y == offset.y + row) {
+            let speed = rng.range(1, 5) as i32;
+            lights.push((pixel, Point::new(rng.range(-5, 5) as i32, speed)));
+            lights.push((pixel, Point::new(rng.range(-5, 5) as i32, -speed)));
+        }
+    }
+
+    rng.shuffle(&mut lights);
+
+    Ok(lights
+        .into_iter()
+        .map(|(pixel, velocity)| {
+            let position = pixel - velocity * seconds as i32;
+            format!(
+                "position=<{:>6}, {:>6}> velocity=<{:>2}, {:>2}>\n",
+                position.x, position.y, velocity.x, velocity.y
+            )
+        })
+        .collect())
+}
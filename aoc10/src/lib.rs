@@ -1,106 +1,131 @@
+use common::{BoundingBox, ParseError, Point, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
-use std::cmp;
 use std::str::FromStr;
 
+mod generate;
+
+pub use generate::message;
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Points;
+    type Part1 = String;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Points::new(input)
+    }
+
+    fn part1(points: &Self::Input) -> Result<Self::Part1> {
+        let mut points = points.clone();
+        points.converge();
+
+        Ok(points.grid())
+    }
+
+    fn part2(points: &Self::Input) -> Result<Self::Part2> {
+        let mut points = points.clone();
+        points.converge();
+
+        Ok(points.second)
+    }
+}
+
+#[derive(Clone)]
 pub struct Points {
-    points: Vec<Point>,
+    lights: Vec<Light>,
     pub second: u32,
 }
 
 impl Points {
     pub fn new(input: &str) -> Result<Self> {
-        let mut points: Vec<Point> = Vec::new();
+        let lights: Vec<Light> = common::parse_lines(input, str::parse)?;
 
-        for line in input.lines() {
-            points.push(line.parse()?);
+        if lights.is_empty() {
+            return Err(From::from(
+                ParseError::unexpected_end("", "at least one point").at_line(1),
+            ));
         }
 
-        Ok(Points { points, second: 0 })
+        Ok(Points { lights, second: 0 })
     }
 
     pub fn step(&mut self) {
-        for p in self.points.iter_mut() {
-            p.step();
+        for light in self.lights.iter_mut() {
+            light.step();
         }
         self.second += 1;
     }
 
-    pub fn bounds(&self) -> BoundingBox {
-        let mut bb = BoundingBox {
-            xmin: self.points[0].x,
-            ymin: self.points[0].y,
-            xmax: self.points[0].x,
-            ymax: self.points[0].y,
-        };
+    pub fn step_back(&mut self) {
+        for light in self.lights.iter_mut() {
+            light.step_back();
+        }
+        self.second -= 1;
+    }
 
-        for p in self.points.iter() {
-            bb.xmin = cmp::min(p.x, bb.xmin);
-            bb.ymin = cmp::min(p.y, bb.ymin);
-            bb.xmax = cmp::max(p.x, bb.xmax);
-            bb.ymax = cmp::max(p.x, bb.ymax);
+    /// Steps the points until their bounding box stops shrinking, leaving them at the second
+    /// where they are packed most tightly together, i.e. when the message appears
+    pub fn converge(&mut self) {
+        let mut height = self.bounds().height();
+
+        loop {
+            self.step();
+            let next = self.bounds().height();
+            if next >= height {
+                self.step_back();
+                break;
+            }
+            height = next;
         }
+    }
 
-        bb
+    pub fn bounds(&self) -> BoundingBox {
+        // there is always at least one light
+        BoundingBox::from_points(self.lights.iter().map(|light| light.position)).unwrap()
     }
 
     pub fn grid(&self) -> String {
-        let bb = self.bounds();
-        let mut grid = vec![vec![b'.'; bb.width()]; bb.height()];
+        let mut grid = self.bounds().grid(false);
 
-        for p in self.points.iter() {
-            let x = (p.x - bb.xmin) as usize;
-            let y = (p.y - bb.ymin) as usize;
-            grid[y][x] = b'#';
+        for light in self.lights.iter() {
+            grid[light.position] = true;
         }
 
-        let mut buf = String::new();
-        for row in grid {
-            unsafe { buf.push_str(std::str::from_utf8_unchecked(&row)) };
-            buf.push('\n');
-        }
-        buf
+        grid.render(|&lit| if lit { '#' } else { '.' })
     }
 }
 
-pub struct BoundingBox {
-    pub xmin: i32,
-    pub ymin: i32,
-    pub xmax: i32,
-    pub ymax: i32,
+// a point of light moving at a constant velocity
+#[derive(Clone)]
+pub struct Light {
+    pub position: Point,
+    pub velocity: Point,
 }
 
-impl BoundingBox {
-    pub fn width(&self) -> usize {
-        (self.xmax - self.xmin + 1) as usize
-    }
-
-    pub fn height(&self) -> usize {
-        (self.ymax - self.ymin + 1) as usize
+impl Light {
+    /// Updates the position of the light in a unit time step
+    pub fn step(&mut self) {
+        self.position += self.velocity;
     }
-}
-
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-    pub vx: i32,
-    pub vy: i32,
-}
 
-impl Point {
-    /// Updates the position of the point in a unit time step
-    pub fn step(&mut self) {
-        self.x += self.vx;
-        self.y += self.vy;
+    /// Reverts a unit time step
+    pub fn step_back(&mut self) {
+        self.position -= self.velocity;
     }
 }
 
-impl FromStr for Point {
-    type Err = Box<dyn std::error::Error>;
+impl FromStr for Light {
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
         lazy_static! {
             static ref RE: Regex = Regex::new(
                 r"(?x)
@@ -114,16 +139,85 @@ impl FromStr for Point {
 
         let caps = match RE.captures(s) {
             Some(caps) => caps,
-            None => return Err(From::from("Unrecognized format for Point.")),
+            None => {
+                return Err(ParseError::unrecognized(
+                    s,
+                    "a point of the form position=<x, y> velocity=<vx, vy>",
+                ))
+            }
+        };
+        let field = |name: &str, expected: &str| {
+            common::number(s, caps.name(name).unwrap().range(), expected)
         };
 
-        let p = Point {
-            x: caps["x"].parse()?,
-            y: caps["y"].parse()?,
-            vx: caps["vx"].parse()?,
-            vy: caps["vy"].parse()?,
+        let light = Light {
+            position: Point::new(field("x", "an x position")?, field("y", "a y position")?),
+            velocity: Point::new(field("vx", "an x velocity")?, field("vy", "a y velocity")?),
         };
 
-        Ok(p)
+        Ok(light)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../input/example.txt");
+
+    #[test]
+    fn part1() {
+        let points = Day10::parse(EXAMPLE).unwrap();
+        let message = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###
+";
+        assert_eq!(Day10::part1(&points).unwrap(), message);
+    }
+
+    #[test]
+    fn part2() {
+        let points = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&points).unwrap(), 3);
+    }
+
+    #[test]
+    fn generated_message() {
+        let mut rng = common::Rng::new(10);
+        let input = message(&mut rng, "HI", 5000).unwrap();
+        let points = Day10::parse(&input).unwrap();
+        let expected = "\
+#..#.###
+#..#..#.
+####..#.
+#..#..#.
+#..#..#.
+#..#.###
+";
+
+        assert_eq!(Day10::part1(&points).unwrap(), expected);
+        assert_eq!(Day10::part2(&points).unwrap(), 5000);
+        assert!(message(&mut rng, "HQ", 10).is_err());
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), text in "[ABCEFGHIJKLOPRSUZ]{1,8}", seconds in 1..2000u32) {
+            let input = message(&mut common::Rng::new(seed), &text, seconds).unwrap();
+            let points = Day10::parse(&input).unwrap();
+
+            prop_assert_eq!(Day10::part1(&points).unwrap(), reference::render(&text));
+            prop_assert_eq!(Day10::part2(&points).unwrap(), seconds);
+            prop_assert_eq!(reference::converge(&points.lights, 2 * seconds as i32), seconds as i32);
+        }
     }
 }
@@ -1,21 +1,24 @@
-use aoc10::{Points, Result};
+use aoc10::{Day10, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-const MAX_HEIGHT: usize = 100;
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
 
-fn main() -> Result<()> {
+fn run() -> Result<()> {
     let mut input = String::new();
     io::stdin().read_to_string(&mut input)?;
 
-    let mut points = Points::new(&input)?;
+    let points = Day10::parse(&input)?;
+
+    println!("{}", Day10::part1(&points)?);
+
+    println!("{}", Day10::part2(&points)?);
 
-    for _ in 0..1_000_000 {
-        let bb = points.bounds();
-        if bb.height() < MAX_HEIGHT {
-            println!("{}", points.grid());
-            println!("{}", points.second);
-        }
-        points.step();
-    }
     Ok(())
 }
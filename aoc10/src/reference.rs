@@ -0,0 +1,46 @@
+// every second tried in turn, and the expected text drawn straight from the font
+
+use crate::generate::FONT;
+use crate::Light;
+use std::collections::HashSet;
+
+// tries every second up to limit, returning the first second at which the lights are least
+// spread out vertically
+pub fn converge(lights: &[Light], limit: i32) -> i32 {
+    let height = |t: i32| {
+        let ys = lights.iter().map(|l| l.position.y + l.velocity.y * t);
+        ys.clone().max().unwrap() - ys.min().unwrap()
+    };
+
+    (0..=limit).min_by_key(|&t| height(t)).unwrap()
+}
+
+// draws text with letters 5 columns apart, trimmed to the lit pixels
+pub fn render(text: &str) -> String {
+    let mut lit = HashSet::new();
+
+    for (i, letter) in text.chars().enumerate() {
+        let glyph = FONT.iter().find(|(c, _)| *c == letter).unwrap().1;
+
+        for (y, row) in glyph.iter().enumerate() {
+            for (x, pixel) in row.chars().enumerate() {
+                if pixel == '#' {
+                    lit.insert((i * 5 + x, y));
+                }
+            }
+        }
+    }
+
+    let min_x = lit.iter().map(|p| p.0).min().unwrap();
+    let max_x = lit.iter().map(|p| p.0).max().unwrap();
+    let mut rendered = String::new();
+
+    for y in 0..6 {
+        for x in min_x..=max_x {
+            rendered.push(if lit.contains(&(x, y)) { '#' } else { '.' });
+        }
+        rendered.push('\n');
+    }
+
+    rendered
+}
@@ -0,0 +1,11 @@
+use crate::Day11;
+use common::{Generator, Rng};
+
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 9999;
+
+    // the grid is always 300x300, so size is instead the largest serial number to pick from
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        format!("{}\n", rng.range(1, size.max(1) as i64))
+    }
+}
@@ -1,7 +1,94 @@
+use common::{Answer, Grid, Solution};
+use serde_json::{json, Value};
+use std::fmt;
+
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = i32;
+    type Part1 = Cell;
+    type Part2 = Square;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = input.lines().next().unwrap_or("");
+        let columns = common::trim_span(line, 0..line.len());
+        let serial =
+            common::number(line, columns, "a grid serial number").map_err(|e| e.at_line(1))?;
+
+        Ok(serial)
+    }
+
+    fn part1(&serial: &Self::Input) -> Result<Self::Part1> {
+        let cells = SummedArea::new(serial);
+        let (_, x, y) = cells.max_for_size(3);
+
+        Ok(Cell { x, y })
+    }
+
+    fn part2(&serial: &Self::Input) -> Result<Self::Part2> {
+        let cells = SummedArea::new(serial);
+        let (_, x, y, size) = cells.maxima().into_iter().max().unwrap();
+
+        Ok(Square { x, y, size })
+    }
+}
+
+// the top-left fuel cell of the most powerful 3x3 square
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl Answer for Cell {
+    fn value(&self) -> Value {
+        json!(self.to_string())
+    }
+
+    fn details(&self) -> Option<Value> {
+        Some(json!({ "x": self.x, "y": self.y }))
+    }
+}
+
+// the top-left fuel cell and size of the most powerful square of any size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Square {
+    pub x: usize,
+    pub y: usize,
+    pub size: usize,
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.size)
+    }
+}
+
+impl Answer for Square {
+    fn value(&self) -> Value {
+        json!(self.to_string())
+    }
+
+    fn details(&self) -> Option<Value> {
+        Some(json!({ "x": self.x, "y": self.y, "size": self.size }))
+    }
+}
+
 pub struct SummedArea {
-    table: Vec<Vec<i32>>,
+    table: Grid<i32>,
 }
 
 impl SummedArea {
@@ -9,24 +96,16 @@ impl SummedArea {
         // grid is 300 x 300 but is also indexed from 1, so rather than dealing with converting we
         // will just have an extra row and column and count from 1 as well.
         let size = 300;
-        let mut table: Vec<Vec<i32>> = vec![vec![0; size + 1]];
+        let mut table: Grid<i32> = Grid::new(size + 1, size + 1, 0);
 
         // a b
         // c d
-        // d = b + c - a
-        for y in 1..=size {
-            let row: Vec<i32> = (0..=size)
-                .scan(0, |acc, x| {
-                    // if the value at (x,y) is d, then acc is equal to c
-                    *acc += if x == 0 {
-                        0
-                    } else {
-                        cell_power(x, y, serial) + table[y - 1][x] - table[y - 1][x - 1]
-                    };
-                    Some(*acc)
-                })
-                .collect();
-            table.push(row);
+        // d = b + c - a + the power of the cell at d
+        for y in 1..=size as i32 {
+            for x in 1..=size as i32 {
+                table[(x, y)] = cell_power(x, y, serial) + table[(x, y - 1)] + table[(x - 1, y)]
+                    - table[(x - 1, y - 1)];
+            }
         }
 
         SummedArea { table }
@@ -35,32 +114,94 @@ impl SummedArea {
     /// returns a vec of tuples where each tuple contains the max fuel for a given size of square
     /// and the coordinates that gave the maxima (fuel, x, y, size)
     pub fn maxima(&self) -> Vec<(i32, usize, usize, usize)> {
-        let mut maxima = vec![];
-        let table_size = self.table.len() - 1;
-
-        for size in 2..=table_size {
-            let mut max_fuel = i32::MIN;
-            let mut coord = (0, 0);
-            for y in 1..=table_size - size + 1 {
-                for x in 1..=table_size - size + 1 {
-                    let fuel = self.table[y + size - 1][x + size - 1] + self.table[y - 1][x - 1]
-                        - self.table[y - 1][x + size - 1]
-                        - self.table[y + size - 1][x - 1];
-                    if fuel > max_fuel {
-                        max_fuel = fuel;
-                        coord = (x, y);
-                    }
+        let table_size = self.table.width() - 1;
+
+        (2..=table_size)
+            .map(|size| {
+                let (max_fuel, x, y) = self.max_for_size(size);
+                (max_fuel, x, y, size)
+            })
+            .collect()
+    }
+
+    /// returns the max fuel for squares of the given size and the coordinates that gave it
+    /// (fuel, x, y)
+    pub fn max_for_size(&self, size: usize) -> (i32, usize, usize) {
+        let table_size = self.table.width() as i32 - 1;
+        let size = size as i32;
+        let mut max_fuel = i32::MIN;
+        let mut coord = (0, 0);
+
+        for y in 1..=table_size - size + 1 {
+            for x in 1..=table_size - size + 1 {
+                let fuel = self.table[(x + size - 1, y + size - 1)] + self.table[(x - 1, y - 1)]
+                    - self.table[(x + size - 1, y - 1)]
+                    - self.table[(x - 1, y + size - 1)];
+                if fuel > max_fuel {
+                    max_fuel = fuel;
+                    coord = (x as usize, y as usize);
                 }
             }
-            maxima.push((max_fuel, coord.0, coord.1, size));
         }
-        maxima
+
+        (max_fuel, coord.0, coord.1)
     }
 }
 
-fn cell_power(x: usize, y: usize, serial: i32) -> i32 {
-    let rack_id = x as i32 + 10;
-    let mut fuel: i32 = rack_id * y as i32 + serial;
+// worked out in i64 as any i32 serial is allowed, the power level itself is a single digit
+fn cell_power(x: i32, y: i32, serial: i32) -> i32 {
+    let rack_id = i64::from(x) + 10;
+    let mut fuel = rack_id * i64::from(y) + i64::from(serial);
     fuel *= rack_id;
-    (fuel / 100) % 10 - 5
+    ((fuel / 100) % 10 - 5) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn cell_power() {
+        assert_eq!(super::cell_power(3, 5, 8), 4);
+        assert_eq!(super::cell_power(122, 79, 57), -5);
+        assert_eq!(super::cell_power(217, 196, 39), 0);
+        assert_eq!(super::cell_power(101, 153, 71), 4);
+        assert_eq!(super::cell_power(300, 300, i32::MAX), 0);
+    }
+
+    #[test]
+    fn part1() {
+        let serial = Day11::parse(include_str!("../input/example.txt")).unwrap();
+        assert_eq!(Day11::part1(&serial).unwrap(), Cell { x: 33, y: 45 });
+        assert_eq!(Day11::part1(&42).unwrap(), Cell { x: 21, y: 61 });
+        assert!(Day11::part1(&Day11::parse("100000000").unwrap()).is_ok());
+    }
+
+    #[test]
+    fn part2() {
+        let serial = Day11::parse(include_str!("../input/example.txt")).unwrap();
+        let square = Square {
+            x: 90,
+            y: 269,
+            size: 16,
+        };
+        assert_eq!(Day11::part2(&serial).unwrap(), square);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(16))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..=4usize) {
+            let (_, serial) = common::generated::<Day11>(seed, 9999);
+            let (fuel, x, y) = SummedArea::new(serial).max_for_size(size);
+
+            prop_assert_eq!(fuel, reference::max_for_size(size as i32, serial));
+            prop_assert_eq!(
+                fuel,
+                reference::square_power(x as i32, y as i32, size as i32, serial)
+            );
+        }
+    }
 }
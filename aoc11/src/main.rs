@@ -1,13 +1,24 @@
-use aoc11::SummedArea;
-
-const SERIAL: i32 = 4842;
+use aoc11::{Day11, Result};
+use common::Solution;
+use std::io::{self, Read};
+use std::process;
 
 fn main() {
-    let cells = SummedArea::new(SERIAL);
-    let maxima = cells.maxima();
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let serial = Day11::parse(&input)?;
+
+    println!("{}", Day11::part1(&serial)?);
 
-    println!("({}, {})", maxima[1].1, maxima[1].2);
+    println!("{}", Day11::part2(&serial)?);
 
-    let max = maxima.into_iter().max().unwrap();
-    println!("({}, {}), {}", max.1, max.2, max.3);
+    Ok(())
 }
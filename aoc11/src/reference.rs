@@ -0,0 +1,25 @@
+// square power summed cell by cell, without a summed-area table
+
+fn power(x: i32, y: i32, serial: i32) -> i32 {
+    let rack_id = x + 10;
+    let hundreds = ((rack_id * y + serial) * rack_id / 100) % 10;
+
+    hundreds - 5
+}
+
+// the total power of a square, summed cell by cell
+pub fn square_power(x: i32, y: i32, size: i32, serial: i32) -> i32 {
+    (y..y + size)
+        .flat_map(|cy| (x..x + size).map(move |cx| (cx, cy)))
+        .map(|(cx, cy)| power(cx, cy, serial))
+        .sum()
+}
+
+// the most powerful square of the given size, summing every square directly
+pub fn max_for_size(size: i32, serial: i32) -> i32 {
+    (1..=301 - size)
+        .flat_map(|y| (1..=301 - size).map(move |x| (x, y)))
+        .map(|(x, y)| square_power(x, y, size, serial))
+        .max()
+        .unwrap()
+}
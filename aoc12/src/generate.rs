@@ -0,0 +1,60 @@
+use crate::{Day12, Pots};
+use common::{Generator, Rng};
+
+// how long a rule set has to settle into a pattern that only shifts along, so that part 2
+// finishes quickly, and how far the plants may spread out in that time
+const SETTLE_WITHIN: u64 = 200;
+const MAX_SPREAD: usize = 200;
+
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 100;
+
+    // an initial state of size pots and a rule for each of the 32 patterns. An empty
+    // neighbourhood always stays empty and rule sets are drawn until one settles down, falling
+    // back to rules that move every plant one pot to the right.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let state: String = (0..size.max(1))
+            .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+            .collect();
+
+        let rules = (0..50)
+            .map(|_| {
+                let mut rules: Vec<bool> = (0..32).map(|_| rng.chance(0.5)).collect();
+                rules[0] = false;
+                rules
+            })
+            .find(|rules| settles(&input(&state, rules)))
+            .unwrap_or_else(|| (0..32).map(|pattern| pattern & 0b01000 != 0).collect());
+
+        input(&state, &rules)
+    }
+}
+
+// the pattern for rule i has a plant wherever i has a 1 bit, most significant bit leftmost
+fn input(state: &str, rules: &[bool]) -> String {
+    let pot = |plant: bool| if plant { '#' } else { '.' };
+    let mut input = format!("initial state: {}\n\n", state);
+
+    for (i, &to) in rules.iter().enumerate() {
+        let from: String = (0..5).rev().map(|bit| pot(i >> bit & 1 == 1)).collect();
+        input.push_str(&format!("{} => {}\n", from, pot(to)));
+    }
+
+    input
+}
+
+fn settles(input: &str) -> bool {
+    let mut pots: Pots = input.parse().unwrap();
+    let width = pots.plants().len();
+
+    while pots.generation < SETTLE_WITHIN && pots.plants().len() <= width + MAX_SPREAD {
+        let plants = pots.plants().to_vec();
+        pots.evolve();
+
+        if pots.plants() == &plants[..] {
+            return true;
+        }
+    }
+
+    false
+}
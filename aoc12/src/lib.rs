@@ -1,46 +1,92 @@
+use common::{ErrorKind, ParseError, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
 
+mod generate;
+
+#[cfg(test)]
+mod reference;
+
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
+pub struct Day12;
+
+// how many generations the plants are simulated for while waiting for them to repeat
+const STEADY_WITHIN: u64 = 1_000;
+
+impl Solution for Day12 {
+    type Input = Pots;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(pots: &Self::Input) -> Result<Self::Part1> {
+        pots.clone().sum_after(20)
+    }
+
+    fn part2(pots: &Self::Input) -> Result<Self::Part2> {
+        pots.clone().sum_after(50_000_000_000)
+    }
+}
+
+#[derive(Clone)]
 pub struct Pots {
     transitions: Vec<Transition>,
     pots: Vec<Pot>,
-    pub generation: u32,
+    pub generation: u64,
     pub first: i32,
 }
 
 impl FromStr for Pots {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
         let prefix = "initial state: ";
         let mut lines = s.lines();
 
         let first_line = match lines.next() {
             Some(line) => line,
             None => {
-                return Err(From::from("Empty input"));
+                return Err(ParseError::unexpected_end("", "initial state: <pots>").at_line(1));
+            }
+        };
+
+        let state = match first_line.strip_prefix(prefix) {
+            Some(state) => state,
+            None => {
+                return Err(
+                    ParseError::unrecognized(first_line, "initial state: <pots>").at_line(1),
+                );
             }
         };
 
         let mut pots: Vec<Pot> = vec![Pot::Empty; 4];
-        let mut initial = first_line[prefix.len()..]
-            .chars()
-            .map(|c| c.try_into())
-            .collect::<Result<Vec<Pot>>>()?;
-        pots.append(&mut initial);
+        for (i, c) in state.char_indices() {
+            let pot =
+                Pot::try_from(c).map_err(|e| e.within(first_line, prefix.len() + i).at_line(1))?;
+            pots.push(pot);
+        }
         pots.push(Pot::Empty);
         pots.push(Pot::Empty);
         pots.push(Pot::Empty);
         pots.push(Pot::Empty);
 
-        lines.next();
+        // the transitions start after a blank line, so on the third line of the input
+        if let Some(line) = lines.next().filter(|line| !line.trim().is_empty()) {
+            return Err(
+                ParseError::unrecognized(line, "a blank line before the transitions").at_line(2),
+            );
+        }
         let transitions = lines
-            .map(|l| l.parse())
-            .collect::<Result<Vec<Transition>>>()?;
+            .enumerate()
+            .map(|(i, l)| l.parse().map_err(|e: ParseError| e.at_line(i + 3)))
+            .collect::<std::result::Result<Vec<Transition>, ParseError>>()?;
 
         Ok(Pots {
             first: -4,
@@ -53,17 +99,17 @@ impl FromStr for Pots {
 
 impl Pots {
     pub fn evolve(&mut self) {
-        // assume that we always have a plant
-
-        let first_plant = self.pots.iter().position(|&p| p == Pot::Plant).unwrap();
-        let last_plant = self.pots.len()
-            - 1
-            - self
-                .pots
-                .iter()
-                .rev()
-                .position(|&p| p == Pot::Plant)
-                .unwrap();
+        // once every plant has died there is nothing left to grow
+        let (first_plant, last_plant) = match (
+            self.pots.iter().position(|&p| p == Pot::Plant),
+            self.pots.iter().rposition(|&p| p == Pot::Plant),
+        ) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                self.generation += 1;
+                return;
+            }
+        };
         // offset -4 for the 4 empty pots, first_plant - 2 as that's where we're starting the
         // iteration
         self.first += first_plant as i32 - 6;
@@ -73,13 +119,13 @@ impl Pots {
             next_gen.push(Pot::Empty);
         }
 
-        for i in first_plant - 2..last_plant + 2 {
+        for i in first_plant - 2..=last_plant + 2 {
+            // patterns without a transition leave the pot empty
             let to = self
                 .transitions
                 .iter()
                 .find(|t| t.is_match(&self.pots[i - 2..i + 3]))
-                .unwrap()
-                .to;
+                .map_or(Pot::Empty, |t| t.to);
 
             next_gen.push(to);
         }
@@ -92,12 +138,72 @@ impl Pots {
         self.generation += 1;
     }
 
-    pub fn sum(&self) -> i32 {
+    /// evolves the pots up to the given generation and returns the sum at that point. Once a
+    /// pattern of plants comes round again, shifted along by some number of pots, every
+    /// generation after it is a shifted copy of one already seen, so the remaining generations
+    /// are worked out from those rather than simulated. Plants that don't repeat within 1000
+    /// generations are an error unless that's far enough.
+    pub fn sum_after(&mut self, generations: u64) -> Result<i64> {
+        let start = self.generation;
+        // the generation each pattern of plants was first seen at
+        let mut seen: HashMap<Vec<Pot>, u64> = HashMap::new();
+        // the first plant, the number of plants and the sum of each generation since the start
+        let mut history: Vec<(i64, i64, i64)> = Vec::new();
+
+        while self.generation < generations {
+            let plants = self.plants().to_vec();
+            let first = self
+                .pots
+                .iter()
+                .position(|&p| p == Pot::Plant)
+                .map_or(0, |i| i as i64)
+                + i64::from(self.first);
+            let count = plants.iter().filter(|&&p| p == Pot::Plant).count() as i64;
+
+            if let Some(&earlier) = seen.get(&plants) {
+                let (period, index) = (self.generation - earlier, (earlier - start) as usize);
+                let shift = first - history[index].0;
+                let remaining = generations - self.generation;
+
+                // the target is a copy of the generation rest after the earlier one, shifted
+                // along once for every period between them
+                let (periods, rest) = (remaining / period + 1, remaining % period);
+                let (_, count, sum) = history[index + rest as usize];
+                return Ok(sum + periods as i64 * shift * count);
+            }
+
+            if self.generation - start >= STEADY_WITHIN {
+                return Err(From::from(format!(
+                    "the plants don't repeat within {} generations",
+                    STEADY_WITHIN
+                )));
+            }
+
+            seen.insert(plants, self.generation);
+            history.push((first, count, self.sum()));
+            self.evolve();
+        }
+
+        Ok(self.sum())
+    }
+
+    // the pots from the first plant to the last plant inclusive
+    fn plants(&self) -> &[Pot] {
+        let first = self.pots.iter().position(|&p| p == Pot::Plant);
+        let last = self.pots.iter().rposition(|&p| p == Pot::Plant);
+
+        match (first, last) {
+            (Some(first), Some(last)) => &self.pots[first..=last],
+            _ => &[],
+        }
+    }
+
+    pub fn sum(&self) -> i64 {
         self.pots
             .iter()
             .enumerate()
             .filter(|(_, &p)| p == Pot::Plant)
-            .map(|(i, _)| i as i32 + self.first)
+            .map(|(i, _)| i as i64 + i64::from(self.first))
             .sum()
     }
 
@@ -115,24 +221,30 @@ impl Pots {
     }
 }
 
-#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
 pub enum Pot {
     Plant,
     Empty,
 }
 
 impl TryFrom<char> for Pot {
-    type Error = Box<dyn std::error::Error>;
+    type Error = ParseError;
 
-    fn try_from(c: char) -> Result<Self> {
+    fn try_from(c: char) -> std::result::Result<Self, ParseError> {
         match c {
             '.' => Ok(Pot::Empty),
             '#' => Ok(Pot::Plant),
-            _ => Err(From::from("Unrecognized symbol")),
+            _ => Err(ParseError::new(
+                ErrorKind::InvalidValue,
+                &c.to_string(),
+                0..c.len_utf8(),
+                "`#` or `.`",
+            )),
         }
     }
 }
 
+#[derive(Clone)]
 pub struct Transition {
     pub from: Vec<Pot>,
     pub to: Pot,
@@ -150,23 +262,116 @@ lazy_static! {
 }
 
 impl FromStr for Transition {
-    type Err = Box<dyn std::error::Error>;
+    type Err = ParseError;
 
-    fn from_str(s: &str) -> Result<Self> {
+    fn from_str(s: &str) -> std::result::Result<Self, ParseError> {
         let caps = match RE.captures(s) {
             Some(caps) => caps,
             None => {
-                return Err(From::from("Unrecognized pattern"));
+                return Err(ParseError::unrecognized(
+                    s,
+                    "a transition of the form <5 pots> => <pot>",
+                ));
             }
         };
 
         let from = caps["from"]
             .chars()
-            .map(|c| c.try_into())
-            .collect::<Result<Vec<Pot>>>()?;
+            .enumerate()
+            .map(|(i, c)| Pot::try_from(c).map_err(|e| e.within(s, i)))
+            .collect::<std::result::Result<Vec<Pot>, ParseError>>()?;
 
-        let to = caps["to"].chars().next().unwrap().try_into()?;
+        let to_offset = caps.name("to").unwrap().start();
+        let to = caps["to"]
+            .chars()
+            .next()
+            .unwrap()
+            .try_into()
+            .map_err(|e: ParseError| e.within(s, to_offset))?;
 
         Ok(Transition { from, to })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{Generator, Rng};
+    use proptest::prelude::*;
+
+    #[test]
+    fn part1() {
+        let pots = Day12::parse(include_str!("../input/example.txt")).unwrap();
+        assert_eq!(Day12::part1(&pots).unwrap(), 325);
+    }
+
+    #[test]
+    fn extinct() {
+        let mut pots: Pots = "initial state: #..#\n\n#.... => .\n...#. => ."
+            .parse()
+            .unwrap();
+        assert_eq!(pots.sum_after(50_000_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn missing_blank_line() {
+        let error = "initial state: #..#\n#.... => .\n...#. => ."
+            .parse::<Pots>()
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.text()), (2, "#.... => ."));
+    }
+
+    // plants that only repeat every other generation, and plants that never repeat
+    #[test]
+    fn steady_states() {
+        let input = "initial state: #\n\n.#... => #\n..#.. => #\n.##.. => #\n...## => .\n..##. => .\n##... => .";
+        let pots = Day12::parse(input).unwrap();
+        for generations in 0..40 {
+            assert_eq!(
+                pots.clone().sum_after(generations).unwrap(),
+                reference::sum_after(input, generations as u32)
+            );
+        }
+        assert!(Day12::part2(&pots).is_ok());
+
+        // every plant spreads to its neighbours, so the plants grow without end
+        let mut input = "initial state: #\n\n".to_string();
+        for pattern in 1..32 {
+            let from: String = (0..5)
+                .rev()
+                .map(|bit| if pattern >> bit & 1 == 1 { '#' } else { '.' })
+                .collect();
+            input += &format!("{} => #\n", from);
+        }
+        let pots = Day12::parse(&input).unwrap();
+        assert_eq!(
+            Day12::part1(&pots).unwrap(),
+            reference::sum_after(&input, 20)
+        );
+        assert!(Day12::part2(&pots).is_err());
+    }
+
+    #[test]
+    fn generated() {
+        for seed in 0..5 {
+            let input = Day12::generate(&mut Rng::new(seed), 50);
+            let pots = Day12::parse(&input).unwrap();
+            Day12::part2(&pots).unwrap();
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn matches_reference(seed in any::<u64>(), size in 1..60usize, generations in 0..300u64) {
+            let (input, mut pots) = common::generated::<Day12>(seed, size);
+
+            prop_assert_eq!(
+                pots.sum_after(generations).unwrap(),
+                reference::sum_after(&input, generations as u32)
+            );
+        }
+    }
+}
@@ -1,27 +1,24 @@
-use aoc12::{Pots, Result};
+use aoc12::{Day12, Result};
+use common::Solution;
 use std::io::{self, Read};
+use std::process;
 
-fn main() -> Result<()> {
+fn main() {
+    if let Err(e) = run() {
+        common::report_error(&*e, "<stdin>");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
     let mut input = String::new();
     io::stdin().read_to_string(&mut input)?;
-    let mut pots: Pots = input.parse()?;
 
-    for _ in 0..200 {
-        pots.evolve();
-        println!(
-            "{}: {}: {}: {}",
-            pots.generation,
-            pots.first,
-            pots.pots_to_string(),
-            pots.sum()
-        );
-    }
+    let pots = Day12::parse(&input)?;
+
+    println!("{}", Day12::part1(&pots)?);
 
-    // too lazy to do this one properly. You can see by visual inspection that it converges on a
-    // steady state after ~100 generations with a linear increase of 25 between generations, so
-    // taking the sum at 100 generations and adding 25 * 49,999,999,900 to it we can get the value
-    // for the 50e12th generation
-    println!("{}", (50_000_000_000i64 - 100) * 25 + 3491);
+    println!("{}", Day12::part2(&pots)?);
 
     Ok(())
 }
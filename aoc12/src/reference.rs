@@ -0,0 +1,39 @@
+// every generation simulated on a set of pot numbers, with no steady state shortcut
+
+use std::collections::{HashMap, HashSet};
+
+// simulates every generation on the set of pot numbers holding plants, looking up each pot's
+// neighbourhood in a table of the rules
+pub fn sum_after(input: &str, generations: u32) -> i64 {
+    let mut lines = input.lines();
+    let state = lines.next().unwrap().trim_start_matches("initial state: ");
+    let mut plants: HashSet<i64> = state
+        .char_indices()
+        .filter(|&(_, c)| c == '#')
+        .map(|(i, _)| i as i64)
+        .collect();
+
+    let rules: HashMap<&str, bool> = lines
+        .skip(1)
+        .map(|line| (&line[..5], line.ends_with('#')))
+        .collect();
+
+    for _ in 0..generations {
+        let (min, max) = match (plants.iter().min(), plants.iter().max()) {
+            (Some(&min), Some(&max)) => (min, max),
+            _ => break,
+        };
+
+        plants = (min - 2..=max + 2)
+            .filter(|pot| {
+                let pattern: String = (pot - 2..=pot + 2)
+                    .map(|p| if plants.contains(&p) { '#' } else { '.' })
+                    .collect();
+
+                rules.get(pattern.as_str()).copied().unwrap_or(false)
+            })
+            .collect();
+    }
+
+    plants.into_iter().sum()
+}
@@ -0,0 +1,29 @@
+use serde_json::{json, Value};
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle. Displaying it gives the answer in the form the puzzle
+/// expects, while `value` and `details` give it in a form that scripts can consume.
+pub trait Answer: Display {
+    /// The answer as a typed JSON value
+    fn value(&self) -> Value;
+
+    /// Any auxiliary data that went into the answer, e.g. the guard and minute behind day 4's
+    /// answers
+    fn details(&self) -> Option<Value> {
+        None
+    }
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn value(&self) -> Value {
+                    json!(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(i32, i64, u32, u64, usize, String);
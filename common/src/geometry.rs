@@ -0,0 +1,224 @@
+use crate::Grid;
+use std::cmp;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point, or a vector between two points, on the 2D integer plane
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    /// Sum of the absolute differences along each axis
+    pub fn manhattan(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Largest absolute difference along either axis, i.e. the number of king's moves between
+    /// the points
+    pub fn chebyshev(&self, other: &Point) -> i32 {
+        cmp::max((self.x - other.x).abs(), (self.y - other.y).abs())
+    }
+
+    /// Straight line distance
+    pub fn euclidean(&self, other: &Point) -> f64 {
+        let dx = f64::from(self.x - other.x);
+        let dy = f64::from(self.y - other.y);
+        dx.hypot(dy)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (i32, i32) {
+    fn from(p: Point) -> Self {
+        (p.x, p.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, k: i32) -> Point {
+        Point::new(self.x * k, self.y * k)
+    }
+}
+
+/// The smallest axis-aligned rectangle containing a set of points, edges included
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    min: Point,
+    max: Point,
+}
+
+impl BoundingBox {
+    /// A box containing only p, to be grown with `expand`
+    pub fn new(p: Point) -> Self {
+        BoundingBox { min: p, max: p }
+    }
+
+    /// The box around every point, None if there are no points
+    pub fn from_points<I: IntoIterator<Item = Point>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bb = BoundingBox::new(points.next()?);
+
+        for p in points {
+            bb.expand(p);
+        }
+
+        Some(bb)
+    }
+
+    /// Grows the box just enough to contain p
+    pub fn expand(&mut self, p: Point) {
+        self.min.x = cmp::min(self.min.x, p.x);
+        self.min.y = cmp::min(self.min.y, p.y);
+        self.max.x = cmp::max(self.max.x, p.x);
+        self.max.y = cmp::max(self.max.y, p.y);
+    }
+
+    /// The top left corner
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    /// The bottom right corner
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    pub fn width(&self) -> usize {
+        (i64::from(self.max.x) - i64::from(self.min.x) + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (i64::from(self.max.y) - i64::from(self.min.y) + 1) as usize
+    }
+
+    /// The number of points in the box
+    pub fn area(&self) -> u64 {
+        self.width() as u64 * self.height() as u64
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+
+    pub fn on_edge(&self, p: &Point) -> bool {
+        self.contains(p)
+            && (p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y)
+    }
+
+    /// The box covering the points in both boxes, None if they don't overlap
+    pub fn intersection(&self, other: &BoundingBox) -> Option<BoundingBox> {
+        let min = Point::new(
+            cmp::max(self.min.x, other.min.x),
+            cmp::max(self.min.y, other.min.y),
+        );
+        let max = Point::new(
+            cmp::min(self.max.x, other.max.x),
+            cmp::min(self.max.y, other.max.y),
+        );
+
+        if min.x > max.x || min.y > max.y {
+            return None;
+        }
+
+        Some(BoundingBox { min, max })
+    }
+
+    /// Returns an iterator over all of the points in (including edge) the bounding box, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (xmin, xmax) = (self.min.x, self.max.x); // lifetime shenanigans
+        (self.min.y..=self.max.y).flat_map(move |y| (xmin..=xmax).map(move |x| Point { x, y }))
+    }
+
+    /// Returns a grid covering the bounding box with every cell set to fill
+    pub fn grid<T: Clone>(&self, fill: T) -> Grid<T> {
+        Grid::with_origin(self.min.into(), self.width(), self.height(), fill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-2, 2));
+
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.euclidean(&b), 5.0);
+        assert_eq!(a - b, Point::new(3, -4));
+        assert_eq!(a + b * 2, Point::new(-3, 2));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = vec![Point::new(1, 1), Point::new(-1, 3), Point::new(2, 0)];
+        let mut bb = BoundingBox::from_points(points).unwrap();
+
+        assert_eq!((bb.min(), bb.max()), (Point::new(-1, 0), Point::new(2, 3)));
+        assert_eq!(bb.area(), 16);
+        assert_eq!(bb.points().count(), 16);
+        assert!(bb.on_edge(&Point::new(0, 3)));
+        assert!(!bb.on_edge(&Point::new(0, 4)));
+        assert!(!bb.on_edge(&Point::new(0, 1)));
+
+        bb.expand(Point::new(4, 4));
+        let other = BoundingBox::from_points(vec![Point::new(3, -5), Point::new(8, 1)]).unwrap();
+        let overlap = bb.intersection(&other).unwrap();
+        assert_eq!(
+            (overlap.min(), overlap.max()),
+            (Point::new(3, 0), Point::new(4, 1))
+        );
+        assert_eq!(bb.intersection(&BoundingBox::new(Point::new(5, 5))), None);
+        assert_eq!(BoundingBox::from_points(vec![]), None);
+    }
+}
@@ -0,0 +1,247 @@
+use crate::Point;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+/// A rectangular grid of cells addressed by (x, y), with x increasing to the right and y
+/// increasing downwards. The top left cell sits at the grid's origin, which may be negative, so
+/// that regions of the plane can be stored without translating their coordinates by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: (i32, i32),
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::with_origin((0, 0), width, height, fill)
+    }
+
+    pub fn with_origin(origin: (i32, i32), width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+            origin,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order, returns None if there are not exactly
+    /// width * height cells
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() != width * height {
+            return None;
+        }
+
+        Some(Grid {
+            cells,
+            width,
+            height,
+            origin: (0, 0),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> (i32, i32) {
+        self.origin
+    }
+
+    pub fn contains(&self, pos: (i32, i32)) -> bool {
+        self.offset(pos).is_some()
+    }
+
+    // the index of pos into cells
+    fn offset(&self, (x, y): (i32, i32)) -> Option<usize> {
+        let dx = i64::from(x) - i64::from(self.origin.0);
+        let dy = i64::from(y) - i64::from(self.origin.1);
+
+        if dx < 0 || dy < 0 || dx >= self.width as i64 || dy >= self.height as i64 {
+            return None;
+        }
+
+        Some(dx as usize + dy as usize * self.width)
+    }
+
+    fn position(&self, offset: usize) -> (i32, i32) {
+        let x = self.origin.0 + (offset % self.width) as i32;
+        let y = self.origin.1 + (offset / self.width) as i32;
+        (x, y)
+    }
+
+    pub fn get(&self, pos: (i32, i32)) -> Option<&T> {
+        self.offset(pos).map(move |i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: (i32, i32)) -> Option<&mut T> {
+        self.offset(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// Whether pos is one of the outermost cells of the grid
+    pub fn on_edge(&self, (x, y): (i32, i32)) -> bool {
+        let (xmax, ymax) = (
+            self.origin.0 + self.width as i32 - 1,
+            self.origin.1 + self.height as i32 - 1,
+        );
+
+        self.contains((x, y))
+            && (x == self.origin.0 || y == self.origin.1 || x == xmax || y == ymax)
+    }
+
+    /// Iterates over the cells in row order
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over the position of every cell in row order
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (0..self.cells.len()).map(move |i| self.position(i))
+    }
+
+    /// Iterates over every cell along with its position in row order
+    pub fn cells(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        let start = self.offset((self.origin.0, y))?;
+        Some(&self.cells[start..start + self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a size of 0, but a grid with no width has no cells to chunk anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates down the column at x, which is empty if x is outside of the grid
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let (start, len) = match self.offset((x, self.origin.1)) {
+            Some(start) => (start, self.cells.len()),
+            None => (0, 0),
+        };
+
+        self.cells[..len]
+            .iter()
+            .skip(start)
+            .step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let x0 = self.origin.0;
+        (0..self.width as i32).map(move |dx| self.column(x0 + dx))
+    }
+
+    /// Iterates over the positions of the up to 8 cells surrounding pos that are in the grid
+    pub fn neighbours(&self, (x, y): (i32, i32)) -> impl Iterator<Item = (i32, i32)> + '_ {
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&n| n != (x, y) && self.contains(n))
+    }
+
+    /// Iterates over the positions of the up to 4 cells directly above, below, left and right of
+    /// pos that are in the grid
+    pub fn orthogonal_neighbours(
+        &self,
+        (x, y): (i32, i32),
+    ) -> impl Iterator<Item = (i32, i32)> + '_ {
+        IntoIterator::into_iter([(x, y - 1), (x - 1, y), (x + 1, y), (x, y + 1)])
+            .filter(move |&n| self.contains(n))
+    }
+
+    /// Renders the grid as text with a line per row, turning each cell into a character with
+    /// `symbol`
+    pub fn render<F: Fn(&T) -> char>(&self, symbol: F) -> String {
+        let mut buf = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows() {
+            buf.extend(row.iter().map(&symbol));
+            buf.push('\n');
+        }
+
+        buf
+    }
+}
+
+/// Indexes the grid by position, panicking if the position is outside of the grid
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (i32, i32)) -> &T {
+        match self.offset(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!("position {:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, pos: (i32, i32)) -> &mut T {
+        match self.offset(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("position {:?} is outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        &self[(p.x, p.y)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        &mut self[(p.x, p.y)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negative_origin() {
+        let mut grid = Grid::with_origin((-2, -1), 4, 3, '.');
+        grid[(-2, -1)] = 'a';
+        grid[(1, 1)] = 'b';
+
+        assert_eq!(grid.get((2, 1)), None);
+        assert_eq!(grid.get((-3, 0)), None);
+        assert!(grid.on_edge((-2, 0)));
+        assert!(!grid.on_edge((0, 0)));
+        assert_eq!(grid.row(-1), Some(&['a', '.', '.', '.'][..]));
+        assert_eq!(grid.column(1).collect::<String>(), "..b");
+        assert_eq!(grid.render(|&c| c), "a...\n....\n...b\n");
+        assert_eq!(grid.positions().last(), Some((1, 1)));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(
+            grid.orthogonal_neighbours((2, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 1)]
+        );
+    }
+}
@@ -0,0 +1,38 @@
+mod answer;
+mod geometry;
+mod grid;
+mod parse;
+mod random;
+
+pub use answer::Answer;
+pub use geometry::{BoundingBox, Point};
+pub use grid::Grid;
+pub use parse::{number, parse_lines, trim_span, words, ErrorKind, ParseError};
+pub use random::{generated, Generator, Rng};
+
+use std::error::Error;
+
+pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+/// A single day's puzzle. The raw input is parsed once into a typed model which both parts are
+/// then solved from, returning their answers rather than printing them.
+pub trait Solution {
+    type Input;
+    type Part1: Answer;
+    type Part2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+/// Prints an error to stderr. Errors in the puzzle input are printed as a diagnostic pointing at
+/// the offending text, `name` identifies where the input was read from.
+pub fn report_error(e: &(dyn Error + 'static), name: &str) {
+    match e.downcast_ref::<ParseError>() {
+        Some(e) => eprintln!("{}", e.diagnostic(name)),
+        None => eprintln!("error: {}", e),
+    }
+}
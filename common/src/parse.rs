@@ -0,0 +1,224 @@
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+use std::ops::Range;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// the text does not have the expected shape at all
+    Unrecognized,
+    /// a numeric field has the right shape but does not fit its type
+    InvalidNumber(ParseIntError),
+    /// a field has the right shape but its value is not allowed
+    InvalidValue,
+    /// the input ended before something that was required
+    UnexpectedEnd,
+}
+
+/// An error in the puzzle input, located by its line and the span of columns within that line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ErrorKind,
+    /// 1-indexed line of the input, 0 if the error has not been placed on a line yet
+    pub line: usize,
+    /// byte range of the offending text within `source`
+    pub columns: Range<usize>,
+    /// the full line of input that the error occurred in
+    pub source: String,
+    /// a description of what the input should have looked like
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new<E: Into<String>>(
+        kind: ErrorKind,
+        source: &str,
+        columns: Range<usize>,
+        expected: E,
+    ) -> Self {
+        ParseError {
+            kind,
+            line: 0,
+            columns,
+            source: source.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error covering the whole of source, for when it doesn't match the expected shape
+    pub fn unrecognized<E: Into<String>>(source: &str, expected: E) -> Self {
+        let columns = trim_span(source, 0..source.len());
+        ParseError::new(ErrorKind::Unrecognized, source, columns, expected)
+    }
+
+    pub fn unexpected_end<E: Into<String>>(source: &str, expected: E) -> Self {
+        let end = source.len();
+        ParseError::new(ErrorKind::UnexpectedEnd, source, end..end, expected)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Places an error that was raised against a fragment of a line into the full line, where
+    /// the fragment starts at offset
+    pub fn within(mut self, source: &str, offset: usize) -> Self {
+        self.columns = self.columns.start + offset..self.columns.end + offset;
+        self.source = source.to_string();
+        self
+    }
+
+    /// The offending text
+    pub fn text(&self) -> &str {
+        &self.source[self.columns.clone()]
+    }
+
+    /// 1-indexed column at which the offending text starts, counted in characters
+    pub fn column(&self) -> usize {
+        self.source[..self.columns.start].chars().count() + 1
+    }
+
+    /// Renders the error in the style of a compiler diagnostic, pointing at the offending text
+    /// within its line of input. `name` identifies the input, e.g. its file name.
+    pub fn diagnostic(&self, name: &str) -> String {
+        let line = self.line.to_string();
+        let gutter = " ".repeat(line.len());
+        let carets = "^".repeat(self.text().chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(),
+            gutter,
+            name,
+            self.line,
+            self.column(),
+            gutter,
+            line,
+            self.source,
+            gutter,
+            " ".repeat(self.column() - 1),
+            carets
+        )
+    }
+
+    fn message(&self) -> String {
+        match &self.kind {
+            ErrorKind::Unrecognized => {
+                format!(
+                    "unrecognized input `{}`, expected {}",
+                    self.text(),
+                    self.expected
+                )
+            }
+            ErrorKind::InvalidNumber(e) => format!(
+                "invalid number `{}` ({}), expected {}",
+                self.text(),
+                e,
+                self.expected
+            ),
+            ErrorKind::InvalidValue => {
+                format!(
+                    "invalid value `{}`, expected {}",
+                    self.text(),
+                    self.expected
+                )
+            }
+            ErrorKind::UnexpectedEnd => {
+                format!("unexpected end of input, expected {}", self.expected)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column(),
+            self.message()
+        )
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            ErrorKind::InvalidNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the number found in `columns` of source
+pub fn number<T, E>(source: &str, columns: Range<usize>, expected: E) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+    E: Into<String>,
+{
+    source[columns.clone()]
+        .parse()
+        .map_err(|e| ParseError::new(ErrorKind::InvalidNumber(e), source, columns, expected))
+}
+
+/// Parses every line of input, numbering the line of any error that occurs
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Splits source on whitespace, yielding each word along with its byte range
+pub fn words(source: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    source.split_whitespace().map(move |word| {
+        // split_whitespace yields subslices of source so the offset can be recovered from the
+        // pointers
+        let start = word.as_ptr() as usize - source.as_ptr() as usize;
+        (start..start + word.len(), word)
+    })
+}
+
+/// Shrinks columns so that it excludes any leading or trailing whitespace in source
+pub fn trim_span(source: &str, columns: Range<usize>) -> Range<usize> {
+    let text = &source[columns.clone()];
+    let start = columns.start + text.len() - text.trim_start().len();
+    let end = columns.start + text.trim_end().len();
+
+    if start > end {
+        columns.start..columns.start
+    } else {
+        start..end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagnostic() {
+        let line = "#2 @ 3,x: 4x4";
+        let e = number::<u32, _>(line, 7..8, "a y offset")
+            .unwrap_err()
+            .at_line(12);
+
+        assert_eq!(e.text(), "x");
+        assert_eq!(e.column(), 8);
+        assert_eq!(
+            e.diagnostic("input.txt"),
+            "error: invalid number `x` (invalid digit found in string), expected a y offset
+  --> input.txt:12:8
+   |
+12 | #2 @ 3,x: 4x4
+   |        ^"
+        );
+    }
+}
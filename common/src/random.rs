@@ -0,0 +1,98 @@
+use crate::Solution;
+
+/// A small seedable pseudo-random number generator (SplitMix64). The same seed always produces
+/// the same sequence, so generated inputs can be reproduced from their seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in 0..n, n must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick a number below 0");
+        ((u128::from(self.next_u64()) * u128::from(n)) >> 64) as u64
+    }
+
+    /// A number in lo..=hi
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+
+        if span > u128::from(u64::MAX) {
+            return self.next_u64() as i64;
+        }
+
+        (lo as i128 + i128::from(self.below(span as u64))) as i64
+    }
+
+    /// An index into a collection of length len, len must not be 0
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability p
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Generates random inputs in the shape of a day's puzzle input, for stress testing solutions
+/// and parsers beyond the single personal input
+pub trait Generator {
+    /// A size giving inputs comparable to the real puzzle input
+    const DEFAULT_SIZE: usize;
+
+    /// Generates a valid input, with size scaling how much input there is. What size counts
+    /// depends on the day, e.g. lines of input or nodes in a tree.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generates an input from a seed and parses it, returning both so property tests can hand the
+/// raw text to a reference solution. Panics naming the seed if the generated input doesn't parse.
+pub fn generated<D: Solution + Generator>(seed: u64, size: usize) -> (String, D::Input) {
+    let input = D::generate(&mut Rng::new(seed), size);
+
+    match D::parse(&input) {
+        Ok(parsed) => (input, parsed),
+        Err(e) => panic!("input generated from seed {} doesn't parse: {}", seed, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let (mut a, mut b) = (Rng::new(7), Rng::new(7));
+        let xs: Vec<_> = (0..100).map(|_| a.range(-3, 3)).collect();
+        let ys: Vec<_> = (0..100).map(|_| b.range(-3, 3)).collect();
+
+        assert_eq!(xs, ys);
+        assert!(xs.iter().all(|x| (-3..=3).contains(x)));
+        assert!((-3..=3).all(|n| xs.contains(&n)));
+    }
+}
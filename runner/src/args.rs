@@ -0,0 +1,230 @@
+use crate::days::Part;
+use common::Result;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+pub const USAGE: &str = "\
+usage: aoc run --day <day> [--part <part>] [--input <file>] [--format <format>]
+       aoc verify [--root <dir>] [--answers <file>] [--record]
+       aoc bench [--day <day>] [--iterations <n>] [--root <dir>] [--format <format>]
+       aoc generate --day <day> [--size <n>] [--seed <n>]
+
+run options:
+    --day <day>         the day to run, 1 to 12
+    --part <part>       only run part 1 or part 2, both parts are run if omitted
+    --input <file>      read the puzzle input from file, stdin is read if omitted or -
+    --format <format>   text or json, defaults to text
+
+verify options:
+    --root <dir>        the repository root holding each day's input/input.txt, defaults to the
+                        root this binary was built from
+    --answers <file>    the recorded answers to check against, defaults to <root>/answers.txt
+    --record            record the current answers instead of checking them
+
+bench options:
+    --day <day>         only benchmark the given day, every day is benchmarked if omitted
+    --iterations <n>    how many times to run each day, defaults to 10
+    --root <dir>        as for verify
+    --format <format>   as for run
+
+generate options:
+    --day <day>         the day to generate an input for
+    --size <n>          how much input to generate, the meaning depends on the day, defaults to
+                        a size comparable to the puzzle input
+    --seed <n>          the seed to generate from, a random seed is used and printed to stderr
+                        if omitted";
+
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Bench(BenchArgs),
+    Generate(GenerateArgs),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(From::from(format!(
+                "invalid format {}, expected text or json",
+                s
+            ))),
+        }
+    }
+}
+
+// the root of the repository this binary was built from
+fn default_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+pub struct RunArgs {
+    pub day: u32,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub format: Format,
+}
+
+impl Command {
+    pub fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self> {
+        match args.next().as_deref() {
+            Some("run") => Ok(Command::Run(RunArgs::parse(args)?)),
+            Some("verify") => Ok(Command::Verify(VerifyArgs::parse(args)?)),
+            Some("bench") => Ok(Command::Bench(BenchArgs::parse(args)?)),
+            Some("generate") => Ok(Command::Generate(GenerateArgs::parse(args)?)),
+            Some(other) => Err(From::from(format!("unrecognized command {}", other))),
+            None => Err(From::from("no command given")),
+        }
+    }
+}
+
+impl RunArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self> {
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut format = Format::Text;
+
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+
+            match flag.as_str() {
+                "--day" => day = Some(value.parse()?),
+                "--part" => part = Some(value.parse()?),
+                "--input" if value == "-" => input = None,
+                "--input" => input = Some(PathBuf::from(value)),
+                "--format" => format = value.parse()?,
+                _ => return Err(From::from(format!("unrecognized option {}", flag))),
+            }
+        }
+
+        Ok(RunArgs {
+            day: day.ok_or("--day is required")?,
+            part,
+            input,
+            format,
+        })
+    }
+}
+
+pub struct VerifyArgs {
+    pub root: PathBuf,
+    pub answers: PathBuf,
+    pub record: bool,
+}
+
+impl VerifyArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self> {
+        let mut root = default_root();
+        let mut answers = None;
+        let mut record = false;
+
+        while let Some(flag) = args.next() {
+            if flag == "--record" {
+                record = true;
+                continue;
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+
+            match flag.as_str() {
+                "--root" => root = PathBuf::from(value),
+                "--answers" => answers = Some(PathBuf::from(value)),
+                _ => return Err(From::from(format!("unrecognized option {}", flag))),
+            }
+        }
+
+        Ok(VerifyArgs {
+            answers: answers.unwrap_or_else(|| root.join("answers.txt")),
+            root,
+            record,
+        })
+    }
+}
+
+pub struct BenchArgs {
+    pub day: Option<u32>,
+    pub iterations: usize,
+    pub root: PathBuf,
+    pub format: Format,
+}
+
+impl BenchArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self> {
+        let mut day = None;
+        let mut iterations = 10;
+        let mut root = default_root();
+        let mut format = Format::Text;
+
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+
+            match flag.as_str() {
+                "--day" => day = Some(value.parse()?),
+                "--iterations" => iterations = value.parse()?,
+                "--root" => root = PathBuf::from(value),
+                "--format" => format = value.parse()?,
+                _ => return Err(From::from(format!("unrecognized option {}", flag))),
+            }
+        }
+
+        if iterations == 0 {
+            return Err(From::from("--iterations must be at least 1"));
+        }
+
+        Ok(BenchArgs {
+            day,
+            iterations,
+            root,
+            format,
+        })
+    }
+}
+
+pub struct GenerateArgs {
+    pub day: u32,
+    pub size: Option<usize>,
+    pub seed: Option<u64>,
+}
+
+impl GenerateArgs {
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Self> {
+        let mut day = None;
+        let mut size = None;
+        let mut seed = None;
+
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+
+            match flag.as_str() {
+                "--day" => day = Some(value.parse()?),
+                "--size" => size = Some(value.parse()?),
+                "--seed" => seed = Some(value.parse()?),
+                _ => return Err(From::from(format!("unrecognized option {}", flag))),
+            }
+        }
+
+        Ok(GenerateArgs {
+            day: day.ok_or("--day is required")?,
+            size,
+            seed,
+        })
+    }
+}
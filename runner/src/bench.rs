@@ -0,0 +1,120 @@
+use crate::args::{BenchArgs, Format};
+use crate::days::{self, Part};
+use common::Result;
+use serde::Serialize;
+use std::fs;
+use std::time::Duration;
+
+/// Timings for one stage of solving a day over every iteration, in nanoseconds
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    fn new(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter().map(|d| d.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        Stats {
+            min_ns: nanos[0],
+            median_ns: nanos[nanos.len() / 2],
+            max_ns: nanos[nanos.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayStats {
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Benchmark {
+    pub iterations: usize,
+    pub days: Vec<DayStats>,
+}
+
+/// Times parsing and both parts of the selected days separately over the requested number of
+/// iterations
+pub fn bench(args: &BenchArgs) -> Result<Benchmark> {
+    let selected = match args.day {
+        Some(day) => day..=day,
+        None => days::DAYS,
+    };
+    let mut benchmark = Benchmark {
+        iterations: args.iterations,
+        days: Vec::new(),
+    };
+
+    for day in selected {
+        let path = days::input_path(&args.root, day);
+        let input = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        let (mut parse, mut part1, mut part2) = (vec![], vec![], vec![]);
+
+        for _ in 0..args.iterations {
+            let report = match days::solve(day, &input, None) {
+                Ok(report) => report,
+                Err(e) => {
+                    common::report_error(&*e, &path.display().to_string());
+                    return Err(From::from(format!("could not benchmark day {}", day)));
+                }
+            };
+            parse.push(report.parse);
+
+            for answer in report.answers {
+                match answer.part {
+                    Part::One => part1.push(answer.elapsed),
+                    Part::Two => part2.push(answer.elapsed),
+                }
+            }
+        }
+
+        benchmark.days.push(DayStats {
+            day,
+            parse: Stats::new(&parse),
+            part1: Stats::new(&part1),
+            part2: Stats::new(&part2),
+        });
+    }
+
+    Ok(benchmark)
+}
+
+pub fn print(benchmark: &Benchmark, format: Format) -> Result<()> {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(benchmark)?),
+        Format::Text => {
+            println!("{} iterations", benchmark.iterations);
+            println!(
+                "{:<5}{:<8}{:>14}{:>14}{:>14}",
+                "day", "stage", "min", "median", "max"
+            );
+
+            for day in benchmark.days.iter() {
+                for (stage, stats) in &[
+                    ("parse", &day.parse),
+                    ("part 1", &day.part1),
+                    ("part 2", &day.part2),
+                ] {
+                    println!(
+                        "{:<5}{:<8}{:>14}{:>14}{:>14}",
+                        format!("{:02}", day.day),
+                        stage,
+                        format!("{:.2?}", Duration::from_nanos(stats.min_ns)),
+                        format!("{:.2?}", Duration::from_nanos(stats.median_ns)),
+                        format!("{:.2?}", Duration::from_nanos(stats.max_ns)),
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use common::{Answer as _, Generator, Result, Rng, Solution};
+use serde_json::{json, Map, Value};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Every day that has a solution
+pub const DAYS: RangeInclusive<u32> = 1..=12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Box<dyn std::error::Error>;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(From::from(format!("invalid part {}, expected 1 or 2", s))),
+        }
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    /// the answer as the puzzle expects it
+    pub value: String,
+    /// the answer as a typed JSON value, along with any auxiliary data behind it
+    pub typed: Value,
+    pub details: Option<Value>,
+    pub elapsed: Duration,
+}
+
+// the answers for a single day along with how long each stage took
+pub struct Report {
+    pub day: u32,
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+impl Report {
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.answers.iter().map(|a| a.elapsed).sum::<Duration>()
+    }
+
+    /// The report as an object with a key for each part that was run, e.g.
+    /// `{"day": 4, "parse_ns": 1630, "parts": {"part1": {"answer": 240, "details": {...}}}}`
+    pub fn to_json(&self) -> Value {
+        let mut parts = Map::new();
+
+        for answer in self.answers.iter() {
+            parts.insert(
+                format!("part{}", answer.part),
+                json!({
+                    "answer": answer.typed,
+                    "details": answer.details,
+                    "elapsed_ns": answer.elapsed.as_nanos() as u64,
+                }),
+            );
+        }
+
+        json!({
+            "day": self.day,
+            "parse_ns": self.parse.as_nanos() as u64,
+            "parts": parts,
+        })
+    }
+}
+
+/// The personal puzzle input for the given day, relative to the root of the repository
+pub fn input_path(root: &Path, day: u32) -> PathBuf {
+    root.join(format!("aoc{:02}", day))
+        .join("input")
+        .join("input.txt")
+}
+
+/// Solves the given day, running only the selected part or both parts if none is given
+pub fn solve(day: u32, input: &str, part: Option<Part>) -> Result<Report> {
+    match day {
+        1 => solve_with::<aoc01::Day01>(day, input, part),
+        2 => solve_with::<aoc02::Day02>(day, input, part),
+        3 => solve_with::<aoc03::Day03>(day, input, part),
+        4 => solve_with::<aoc04::Day04>(day, input, part),
+        5 => solve_with::<aoc05::Day05>(day, input, part),
+        6 => solve_with::<aoc06::Day06>(day, input, part),
+        7 => solve_with::<aoc07::Day07>(day, input, part),
+        8 => solve_with::<aoc08::Day08>(day, input, part),
+        9 => solve_with::<aoc09::Day09>(day, input, part),
+        10 => solve_with::<aoc10::Day10>(day, input, part),
+        11 => solve_with::<aoc11::Day11>(day, input, part),
+        12 => solve_with::<aoc12::Day12>(day, input, part),
+        _ => Err(From::from(format!("no solution for day {}", day))),
+    }
+}
+
+/// Generates a random input for the given day from seed, of the day's default size if none is
+/// given
+pub fn generate(day: u32, seed: u64, size: Option<usize>) -> Result<String> {
+    match day {
+        1 => Ok(generate_with::<aoc01::Day01>(seed, size)),
+        2 => Ok(generate_with::<aoc02::Day02>(seed, size)),
+        3 => Ok(generate_with::<aoc03::Day03>(seed, size)),
+        4 => Ok(generate_with::<aoc04::Day04>(seed, size)),
+        5 => Ok(generate_with::<aoc05::Day05>(seed, size)),
+        6 => Ok(generate_with::<aoc06::Day06>(seed, size)),
+        7 => Ok(generate_with::<aoc07::Day07>(seed, size)),
+        8 => Ok(generate_with::<aoc08::Day08>(seed, size)),
+        9 => Ok(generate_with::<aoc09::Day09>(seed, size)),
+        10 => Ok(generate_with::<aoc10::Day10>(seed, size)),
+        11 => Ok(generate_with::<aoc11::Day11>(seed, size)),
+        12 => Ok(generate_with::<aoc12::Day12>(seed, size)),
+        _ => Err(From::from(format!("no generator for day {}", day))),
+    }
+}
+
+fn generate_with<G: Generator>(seed: u64, size: Option<usize>) -> String {
+    G::generate(&mut Rng::new(seed), size.unwrap_or(G::DEFAULT_SIZE))
+}
+
+fn solve_with<S: Solution>(day: u32, input: &str, part: Option<Part>) -> Result<Report> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+    let mut answers = Vec::new();
+
+    if part != Some(Part::Two) {
+        let start = Instant::now();
+        let answer = S::part1(&parsed)?;
+        let elapsed = start.elapsed();
+        answers.push(Answer {
+            part: Part::One,
+            value: answer.to_string(),
+            typed: answer.value(),
+            details: answer.details(),
+            elapsed,
+        });
+    }
+
+    if part != Some(Part::One) {
+        let start = Instant::now();
+        let answer = S::part2(&parsed)?;
+        let elapsed = start.elapsed();
+        answers.push(Answer {
+            part: Part::Two,
+            value: answer.to_string(),
+            typed: answer.value(),
+            details: answer.details(),
+            elapsed,
+        });
+    }
+
+    Ok(Report {
+        day,
+        parse,
+        answers,
+    })
+}
@@ -0,0 +1,114 @@
+mod args;
+mod bench;
+mod days;
+mod verify;
+
+use args::{BenchArgs, Command, Format, GenerateArgs, RunArgs, VerifyArgs, USAGE};
+use common::Result;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::SystemTime;
+use std::{env, process};
+
+fn main() {
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // errors are reported against the name of the input they occurred in
+    let (result, name) = match command {
+        Command::Run(args) => (run(&args), input_name(args.input.as_deref())),
+        Command::Verify(args) => (verify(&args), args.answers.display().to_string()),
+        Command::Bench(args) => (bench(&args), args.root.display().to_string()),
+        Command::Generate(args) => (generate(&args), String::from("<generate>")),
+    };
+
+    if let Err(e) = result {
+        common::report_error(&*e, &name);
+        process::exit(1);
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let input = read_input(args.input.as_deref())?;
+    let report = days::solve(args.day, &input, args.part)?;
+
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&report.to_json())?);
+        return Ok(());
+    }
+
+    println!("Day {:02}", report.day);
+    for answer in report.answers.iter() {
+        // multi-line answers, such as the message from day 10, start on their own line
+        if answer.value.contains('\n') {
+            println!("Part {}: ({:?})", answer.part, answer.elapsed);
+            println!("{}", answer.value.trim_end());
+        } else {
+            println!(
+                "Part {}: {} ({:?})",
+                answer.part, answer.value, answer.elapsed
+            );
+        }
+    }
+    println!("Time: {:?} (parse {:?})", report.elapsed(), report.parse);
+
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<()> {
+    if verify::verify(args)? {
+        Ok(())
+    } else {
+        Err(From::from("some answers did not match those recorded"))
+    }
+}
+
+fn bench(args: &BenchArgs) -> Result<()> {
+    let benchmark = bench::bench(args)?;
+
+    bench::print(&benchmark, args.format)
+}
+
+fn generate(args: &GenerateArgs) -> Result<()> {
+    let seed = match args.seed {
+        Some(seed) => seed,
+        None => {
+            // print the seed so that an interesting input can be generated again
+            let seed = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)?
+                .as_nanos() as u64;
+            eprintln!("seed: {}", seed);
+            seed
+        }
+    };
+
+    print!("{}", days::generate(args.day, seed, args.size)?);
+
+    Ok(())
+}
+
+// how the input is referred to when reporting errors in it
+fn input_name(path: Option<&Path>) -> String {
+    match path {
+        Some(path) => path.display().to_string(),
+        None => String::from("<stdin>"),
+    }
+}
+
+fn read_input(path: Option<&Path>) -> Result<String> {
+    match path {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|e| From::from(format!("could not read {}: {}", path.display(), e))),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use crate::args::VerifyArgs;
+use crate::days::{self, Part};
+use common::{ErrorKind, ParseError, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const HEADER: &str = "\
+# recorded answers for each day's personal input, checked by `aoc verify`
+# each line is <day>.<part>: <answer>, with newlines in an answer written as \\n
+";
+
+/// Answers keyed by day and part, as stored in the answers file
+#[derive(Default)]
+pub struct Answers {
+    inner: BTreeMap<(u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let s = fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+        let mut answers = Answers::default();
+
+        for (i, line) in s.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = parse_entry(line).map_err(|e| e.at_line(i + 1))?;
+            answers.inner.insert(key, value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut s = String::from(HEADER);
+
+        for ((day, part), value) in self.inner.iter() {
+            s.push_str(&format!("{:02}.{}: {}\n", day, part, escape(value)));
+        }
+
+        fs::write(path, s)
+            .map_err(|e| From::from(format!("could not write {}: {}", path.display(), e)))
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.inner.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u32, part: Part, value: String) {
+        self.inner.insert((day, part), value);
+    }
+}
+
+// an entry of the form 07.2: 898
+fn parse_entry(line: &str) -> std::result::Result<((u32, Part), String), ParseError> {
+    let expected = "an answer of the form <day>.<part>: <answer>";
+    let (colon, dot) = match (line.find(": "), line.find('.')) {
+        (Some(colon), Some(dot)) if dot < colon => (colon, dot),
+        _ => return Err(ParseError::unrecognized(line, expected)),
+    };
+
+    let day = common::number(line, 0..dot, "a day")?;
+    let part = match &line[dot + 1..colon] {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => {
+            return Err(ParseError::new(
+                ErrorKind::InvalidValue,
+                line,
+                dot + 1..colon,
+                "part 1 or 2",
+            ))
+        }
+    };
+
+    Ok(((day, part), unescape(&line[colon + 2..])))
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(value: &str) -> String {
+    let mut s = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                s.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                s.push('\\');
+                chars.next();
+            }
+            _ => s.push(c),
+        }
+    }
+
+    s
+}
+
+/// Runs every day against its personal input, checking the answers against those recorded, or
+/// recording them if asked to. Returns whether every answer matched.
+pub fn verify(args: &VerifyArgs) -> Result<bool> {
+    let recorded = if args.record {
+        Answers::default()
+    } else {
+        Answers::load(&args.answers)?
+    };
+    let mut actual = Answers::default();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for day in days::DAYS {
+        let path = days::input_path(&args.root, day);
+        let report = fs::read_to_string(&path)
+            .map_err(|e| From::from(format!("could not read {}: {}", path.display(), e)))
+            .and_then(|input| days::solve(day, &input, None));
+
+        let report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                println!("Day {:02}: FAILED, {}", day, e);
+                failed += 2;
+                continue;
+            }
+        };
+
+        for answer in report.answers {
+            if args.record {
+                println!("Day {:02} part {}: recorded", day, answer.part);
+            } else {
+                match recorded.get(day, answer.part) {
+                    Some(expected) if expected == answer.value => {
+                        println!("Day {:02} part {}: ok", day, answer.part);
+                        passed += 1;
+                    }
+                    Some(expected) => {
+                        println!("Day {:02} part {}: FAILED", day, answer.part);
+                        print_diff(expected, &answer.value);
+                        failed += 1;
+                    }
+                    None => {
+                        println!("Day {:02} part {}: no recorded answer", day, answer.part);
+                        missing += 1;
+                    }
+                }
+            }
+
+            actual.insert(day, answer.part, answer.value);
+        }
+    }
+
+    if args.record {
+        actual.save(&args.answers)?;
+        println!("\nrecorded answers to {}", args.answers.display());
+    } else {
+        println!(
+            "\n{} passed, {} failed, {} missing",
+            passed, failed, missing
+        );
+    }
+
+    Ok(failed == 0)
+}
+
+// prints the lines of the expected and actual answers that differ
+fn print_diff(expected: &str, actual: &str) {
+    let (expected, actual): (Vec<_>, Vec<_>) =
+        (expected.lines().collect(), actual.lines().collect());
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => println!("      {}", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    println!("    - {}", e);
+                }
+                if let Some(a) = a {
+                    println!("    + {}", a);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        // a multi-line answer like day 10's, with backslashes that must survive escaping
+        let value = "#..#\n\\n is not a newline\n\\\\";
+        let mut answers = Answers::default();
+        answers.insert(10, Part::Two, value.to_string());
+        answers.insert(3, Part::One, "110891".to_string());
+
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+        answers.save(&path).unwrap();
+        let saved = fs::read_to_string(&path).unwrap();
+        let loaded = Answers::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(saved.ends_with("03.1: 110891\n10.2: #..#\\n\\\\n is not a newline\\n\\\\\\\\\n"));
+        assert_eq!(loaded.get(10, Part::Two), Some(value));
+        assert_eq!(loaded.get(3, Part::One), Some("110891"));
+        assert_eq!(loaded.get(3, Part::Two), None);
+    }
+
+    #[test]
+    fn entries() {
+        assert_eq!(
+            parse_entry("07.2: 898").unwrap(),
+            ((7, Part::Two), "898".to_string())
+        );
+        assert_eq!(unescape(&escape("a\\nb\nc\\")), "a\\nb\nc\\");
+
+        let error = parse_entry("07.3: 898").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidValue);
+        assert_eq!(error.text(), "3");
+
+        let error = parse_entry("07.2 898").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Unrecognized);
+        let error = parse_entry("07: 1.5").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::Unrecognized);
+    }
+}